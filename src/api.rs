@@ -1,13 +1,177 @@
 use crate::client::{self, Client};
 
-const GW2_API_DOMAIN: &str = "https://api.guildwars2.com";
+/// Definitions for the authenticated /v2/account endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/account
+pub mod account;
+
+/// Definitions for the /v2/backstory endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/backstory
+pub mod backstory;
+
+/// Definitions for the /v2/build endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/build
+pub mod build;
+
+/// Definitions for the authenticated /v2/characters endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/characters
+pub mod characters;
+
+/// Definitions for the /v2/colors endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/colors
+pub mod colors;
+
+/// Definitions for the /v2/continents endpoints (continents, floors, regions, maps, sectors,
+/// points of interest and tasks).
+/// See: https://wiki.guildwars2.com/wiki/API:2/continents
+pub mod continents;
 
-pub fn build_url(endpoint: &str) -> String {
-    format!("{}{}", GW2_API_DOMAIN, endpoint)
-}
+/// Definitions for the authenticated /v2/createsubtoken endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/createsubtoken
+pub mod createsubtoken;
+
+/// Definitions for the /v2/dailycrafting endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/dailycrafting
+pub mod dailycrafting;
+
+/// Definitions for the /v2/dungeons endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/dungeons
+pub mod dungeons;
+
+/// Definitions for the /v2/emblem endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/emblem
+pub mod emblem;
+
+/// Definitions for the /v2/emotes endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/emotes
+pub mod emotes;
+
+/// Definitions for the /v2/files endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/files
+pub mod files;
+
+/// Definitions for the /v2/finishers endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/finishers
+pub mod finishers;
+
+/// Definitions for the /v2/gliders endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/gliders
+pub mod gliders;
+
+/// Definitions for the /v2/guild endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/guild/:id
+pub mod guild;
+
+/// Definitions for the /v2/home endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/home
+pub mod home;
+
+/// Definitions for the static /v2/homestead endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/homestead
+pub mod homestead;
+
+/// Definitions for the /v2/items endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/items
+pub mod items;
+
+/// Definitions for the /v2/jadebots endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/jadebots
+pub mod jadebots;
+
+/// Definitions for the /v2/legends endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/legends
+pub mod legends;
+
+/// Definitions for the /v2/mailcarriers endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/mailcarriers
+pub mod mailcarriers;
+
+/// Definitions for the /v2/mapchests endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/mapchests
+pub mod mapchests;
+
+/// Definitions for the /v2/maps endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/maps
+pub mod maps;
+
+/// Definitions for the /v2/masteries endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/masteries
+pub mod masteries;
+
+/// Definitions for the /v2/materials endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/materials
+pub mod materials;
+
+/// Definitions for the /v2/minis endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/minis
+pub mod minis;
+
+/// Definitions for the /v2/mounts endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/mounts
+pub mod mounts;
+
+/// Definitions for the /v2/novelties endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/novelties
+pub mod novelties;
+
+/// Definitions for the /v2/outfits endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/outfits
+pub mod outfits;
+
+/// Definitions for the /v2/pvp endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/pvp
+pub mod pvp;
+
+/// Definitions for the /v2/quaggans endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/quaggans
+pub mod quaggans;
+
+/// Definitions for the /v2/quests endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/quests
+pub mod quests;
+
+/// Definitions for the /v2/raids endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/raids
+pub mod raids;
+
+/// Definitions for the /v2/recipes endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/recipes
+pub mod recipes;
+
+/// Definitions for the /v2/skiffs endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/skiffs
+pub mod skiffs;
+
+/// Definitions for the /v2/skins endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/skins
+pub mod skins;
+
+/// Definitions for the /v2/stories endpoints. Character progress through stories is
+/// reported by `characters::get_quests`, resolved via `quests`.
+/// See: https://wiki.guildwars2.com/wiki/API:2/stories
+pub mod stories;
+
+/// Definitions for the /v2/titles endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/titles
+pub mod titles;
+
+/// Definitions for the authenticated /v2/tokeninfo endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/tokeninfo
+pub mod tokeninfo;
+
+/// Definitions for the static /v2/wizardsvault endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wizardsvault
+pub mod wizardsvault;
+
+/// Definitions for the /v2/worldbosses endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/worldbosses
+pub mod worldbosses;
+
+/// Definitions for the /v2/wvw endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wvw
+pub mod wvw;
 
 /// Represents a Guild Wars 2 Item ID.
-#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ItemId(pub u32);
 
 impl std::fmt::Display for ItemId {
@@ -16,12 +180,239 @@ impl std::fmt::Display for ItemId {
     }
 }
 
+/// Maximum number of ids the API accepts in a single `?ids=` request.
+pub const MAX_IDS_PER_REQUEST: usize = 200;
+
+/// Error type for bulk `?ids=` requests.
+#[derive(thiserror::Error, Debug)]
+pub enum GetManyError {
+    #[error("max of 200 ids are allowed, got {0}")]
+    TooManyIds(usize),
+    #[error("client error: {0}")]
+    ClientError(#[from] client::GetError),
+}
+
+/// Joins ids into the comma separated form expected by `?ids=`.
+fn join_ids<Id: std::fmt::Display>(ids: &[Id]) -> String {
+    use std::fmt::Write;
+
+    ids.iter().fold(String::new(), |mut acc, id| {
+        if !acc.is_empty() {
+            acc.push(',');
+        }
+
+        write!(&mut acc, "{}", id).expect("writing id to String should not fail");
+
+        acc
+    })
+}
+
+/// Sorts and dedupes ids, so that requests for the same set of ids share a url and with it
+/// the client's cache and request coalescing.
+fn normalize_ids<Id: Ord + Clone>(ids: &[Id]) -> Vec<Id> {
+    let mut ids = ids.to_vec();
+    ids.sort_unstable();
+    ids.dedup();
+    ids
+}
+
+/// Orders entries like the ids they were requested with, keeping the first entry of ids
+/// requested more than once.
+fn in_request_order<T: Identified>(ids: &[T::Id], entries: Vec<T>) -> Vec<T> {
+    let mut by_id: std::collections::HashMap<_, _> = entries
+        .into_iter()
+        .map(|entry| (entry.id().clone(), entry))
+        .collect();
+
+    ids.iter().filter_map(|id| by_id.remove(id)).collect()
+}
+
+/// Fetches multiple entries of a bulk-expanded endpoint by id. Ids are sorted and deduped
+/// before the request, and entries are mapped back to the order of `ids`. Ids without an
+/// entry are left out, and ids requested more than once get their entry once, at their
+/// first position.
+/// Corresponds to GET {endpoint}?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+async fn get_many<Id, Response>(
+    client: &Client,
+    endpoint: &str,
+    ids: &[Id],
+) -> Result<Vec<Response>, GetManyError>
+where
+    Id: std::fmt::Display + Ord + std::hash::Hash + Clone,
+    Response: serde::de::DeserializeOwned + Identified<Id = Id>,
+{
+    let normalized = normalize_ids(ids);
+    if normalized.len() > MAX_IDS_PER_REQUEST {
+        return Err(GetManyError::TooManyIds(normalized.len()));
+    }
+
+    if normalized.is_empty() {
+        return Ok(Vec::new());
+    }
+
+    let entries = client
+        .get(&client.url(&format!("{}?ids={}", endpoint, join_ids(&normalized))))
+        .await?;
+
+    Ok(in_request_order(ids, entries))
+}
+
+/// An entry of a bulk-expanded endpoint, identified by the id it was requested with.
+pub trait Identified {
+    type Id: Eq + std::hash::Hash + Clone;
+
+    fn id(&self) -> &Self::Id;
+}
+
+/// Entries fetched from a bulk-expanded endpoint, along with the requested ids the API had
+/// no entry for. The API answers such requests with 206 Partial Content if some ids are
+/// unknown, and with 404 if all of them are. Ids requested more than once are only
+/// reported once, at their first position.
+#[derive(Debug)]
+pub struct BulkResult<Id, T> {
+    /// Entries that were found, in the order they were requested.
+    pub found: Vec<T>,
+    /// Requested ids without an entry, in the order they were requested.
+    pub missing: Vec<Id>,
+    /// Requested ids that were never fetched because the fetch was cancelled, in the order
+    /// they were requested.
+    pub unfetched: Vec<Id>,
+}
+
+impl<Id, T> BulkResult<Id, T> {
+    /// Whether every requested id was fetched and found.
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.unfetched.is_empty()
+    }
+
+    /// Whether the fetch was cancelled before every id was requested.
+    pub fn is_cancelled(&self) -> bool {
+        !self.unfetched.is_empty()
+    }
+}
+
+/// Fetches any number of entries of a bulk-expanded endpoint by id, split into requests of
+/// at most `MAX_IDS_PER_REQUEST` ids. Up to `concurrency` requests are in flight at once
+/// (still subject to the client's rate limiter). Ids are sorted and deduped before being
+/// chunked, and results are mapped back to the order of `ids`. Once `cancel` is cancelled,
+/// chunks that haven't completed are dropped and their ids reported as unfetched.
+async fn get_many_chunked<Id, Response>(
+    client: &Client,
+    endpoint: &str,
+    ids: &[Id],
+    concurrency: usize,
+    cancel: Option<&client::CancellationToken>,
+) -> Result<BulkResult<Id, Response>, client::GetError>
+where
+    Id: std::fmt::Display + Ord + std::hash::Hash + Clone,
+    Response: serde::de::DeserializeOwned + Identified<Id = Id>,
+{
+    fetch_chunked(client, endpoint, ids, concurrency, cancel, false).await
+}
+
+/// Like [`get_many_chunked`], for endpoints that only change with game builds (items,
+/// recipes, skins, ...): chunks are fetched through [`Client::get_cached`], so they are
+/// served from the client's disk cache, if any, until the next game update.
+async fn get_static_chunked<Id, Response>(
+    client: &Client,
+    endpoint: &str,
+    ids: &[Id],
+) -> Result<BulkResult<Id, Response>, client::GetError>
+where
+    Id: std::fmt::Display + Ord + std::hash::Hash + Clone,
+    Response: serde::de::DeserializeOwned + Identified<Id = Id>,
+{
+    fetch_chunked(client, endpoint, ids, 1, None, true).await
+}
+
+async fn fetch_chunked<Id, Response>(
+    client: &Client,
+    endpoint: &str,
+    ids: &[Id],
+    concurrency: usize,
+    cancel: Option<&client::CancellationToken>,
+    cached: bool,
+) -> Result<BulkResult<Id, Response>, client::GetError>
+where
+    Id: std::fmt::Display + Ord + std::hash::Hash + Clone,
+    Response: serde::de::DeserializeOwned + Identified<Id = Id>,
+{
+    use futures_util::{StreamExt, TryStreamExt};
+
+    let normalized = normalize_ids(ids);
+    let chunks: Vec<(&[Id], Option<Vec<Response>>)> =
+        futures_util::stream::iter(normalized.chunks(MAX_IDS_PER_REQUEST))
+            .map(|chunk| {
+                let url = client.url(&format!("{}?ids={}", endpoint, join_ids(chunk)));
+                async move {
+                    let fetch = async {
+                        let response = if cached {
+                            client.get_cached::<Vec<Response>>(&url).await
+                        } else {
+                            client.get::<Vec<Response>>(&url).await
+                        };
+                        match response {
+                            // Returned when none of the ids exist
+                            Err(client::GetError::Api {
+                                error: client::ApiError::NotFound(_),
+                                ..
+                            }) => Ok(Vec::new()),
+                            result => result,
+                        }
+                    };
+                    let entries = match cancel {
+                        Some(cancel) => tokio::select! {
+                            biased;
+                            _ = cancel.cancelled() => None,
+                            entries = fetch => Some(entries?),
+                        },
+                        None => Some(fetch.await?),
+                    };
+
+                    Ok::<_, client::GetError>((chunk, entries))
+                }
+            })
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await?;
+
+    let mut by_id = std::collections::HashMap::new();
+    let mut unfetched = std::collections::HashSet::new();
+    for (chunk, entries) in chunks {
+        match entries {
+            Some(entries) => {
+                by_id.extend(entries.into_iter().map(|entry| (entry.id().clone(), entry)))
+            }
+            None => unfetched.extend(chunk),
+        }
+    }
+
+    let mut result = BulkResult {
+        found: Vec::with_capacity(by_id.len()),
+        missing: Vec::new(),
+        unfetched: Vec::new(),
+    };
+    let mut seen = std::collections::HashSet::new();
+    for id in ids.iter().filter(|id| seen.insert(*id)) {
+        if unfetched.contains(id) {
+            result.unfetched.push(id.clone());
+            continue;
+        }
+        match by_id.remove(id) {
+            Some(entry) => result.found.push(entry),
+            None => result.missing.push(id.clone()),
+        }
+    }
+
+    Ok(result)
+}
+
 /// Definitions for the /v2/commerce/listings endpoint.
 /// See: https://wiki.guildwars2.com/wiki/API:2/commerce/listings
 pub mod listings {
-    use std::fmt::Write;
-
     use super::*;
+    use crate::coin::Coin;
 
     #[derive(thiserror::Error, Debug)]
     pub enum GetManyListingsError {
@@ -36,8 +427,8 @@ pub mod listings {
         /// The number of individual listings this object refers to (e.g. two players selling at
         /// the same price will end up in the same listing)
         pub listings: u32,
-        /// The sell offer or buy order price in coins.
-        pub unit_price: u32,
+        /// The sell offer or buy order price.
+        pub unit_price: Coin,
         /// The amount of items being sold/bought in this listing.
         pub quantity: u32,
     }
@@ -56,15 +447,31 @@ pub mod listings {
     /// Fetches all item IDs that have listings on the trading post.
     /// Corresponds to GET /v2/commerce/listings
     pub async fn get_all_ids(client: &Client) -> Result<Vec<ItemId>, client::GetError> {
-        Ok(client.get(&build_url("/v2/commerce/listings")).await?)
+        client.get(&client.url("/v2/commerce/listings")).await
     }
 
     /// Fetches all items that have listings on the trading post.
     /// Corresponds to paginated GET /v2/commerce/listings
     pub async fn get_all(client: &Client) -> Result<Vec<Listings>, client::PaginatedGetError> {
-        Ok(client
-            .get_all_pages(&build_url("/v2/commerce/listings"), Default::default())
-            .await?)
+        client
+            .get_all_pages(&client.url("/v2/commerce/listings"), Default::default())
+            .await
+    }
+
+    /// Streams all items that have listings on the trading post, page by page.
+    /// Corresponds to paginated GET /v2/commerce/listings
+    pub fn stream_all(
+        client: &Client,
+    ) -> impl futures_util::Stream<Item = Result<Listings, client::PaginatedGetError>> + '_ {
+        client.stream_pages(&client.url("/v2/commerce/listings"), Default::default())
+    }
+
+    impl Identified for Listings {
+        type Id = ItemId;
+
+        fn id(&self) -> &ItemId {
+            &self.id
+        }
     }
 
     /// Fetches the buy and sell listings for a single item ID.
@@ -74,47 +481,78 @@ pub mod listings {
         item_id: &ItemId,
     ) -> Result<Listings, client::GetError> {
         client
-            .get(&build_url(&format!("/v2/commerce/listings/{}", item_id)))
+            .get(&client.url(&format!("/v2/commerce/listings/{}", item_id)))
             .await
     }
 
-    /// Fetches the buy and sell listings for multiple item IDs.
+    /// Fetches the buy and sell listings for multiple item IDs, in the order of `item_ids`.
     /// Corresponds to GET /v2/commerce/listings?ids=...
-    /// Note: The API limits the number of IDs per request to 200.
+    /// Note: The API limits the number of distinct IDs per request to 200.
     pub async fn get_many_listings(
         client: &Client,
         item_ids: &[ItemId],
     ) -> Result<Vec<Listings>, GetManyListingsError> {
-        if item_ids.len() > 200 {
-            return Err(GetManyListingsError::TooManyListingIds(item_ids.len()));
+        let ids = normalize_ids(item_ids);
+        if ids.len() > MAX_IDS_PER_REQUEST {
+            return Err(GetManyListingsError::TooManyListingIds(ids.len()));
         }
 
-        if item_ids.is_empty() {
+        if ids.is_empty() {
             return Ok(Vec::new());
         }
 
-        let param = item_ids.iter().fold(String::new(), |mut acc, id| {
-            if !acc.is_empty() {
-                acc.push(',');
-            }
+        let listings = client
+            .get(&client.url(&format!("/v2/commerce/listings?ids={}", join_ids(&ids))))
+            .await?;
+        Ok(in_request_order(item_ids, listings))
+    }
 
-            write!(&mut acc, "{}", id).expect("writing ItemId to String should not fail");
+    /// Fetches the buy and sell listings for any number of item IDs, split into requests
+    /// of at most 200 IDs. IDs without listings are reported as missing.
+    /// Corresponds to GET /v2/commerce/listings?ids=...
+    pub async fn get_listings_chunked(
+        client: &Client,
+        item_ids: &[ItemId],
+    ) -> Result<BulkResult<ItemId, Listings>, client::GetError> {
+        get_many_chunked(client, "/v2/commerce/listings", item_ids, 1, None).await
+    }
 
-            acc
-        });
+    /// Like [`get_listings_chunked`], but with up to `concurrency` requests in flight at
+    /// once. Results keep the order of `item_ids`.
+    /// Corresponds to GET /v2/commerce/listings?ids=...
+    pub async fn get_listings_parallel(
+        client: &Client,
+        item_ids: &[ItemId],
+        concurrency: usize,
+    ) -> Result<BulkResult<ItemId, Listings>, client::GetError> {
+        get_many_chunked(client, "/v2/commerce/listings", item_ids, concurrency, None).await
+    }
 
-        Ok(client
-            .get(&build_url(&format!("/v2/commerce/listings?ids={}", param)))
-            .await?)
+    /// Like [`get_listings_parallel`], but stops once `cancel` is cancelled. IDs that were
+    /// never fetched are reported in `unfetched`, alongside the listings fetched until then.
+    /// Corresponds to GET /v2/commerce/listings?ids=...
+    pub async fn get_listings_cancellable(
+        client: &Client,
+        item_ids: &[ItemId],
+        concurrency: usize,
+        cancel: &client::CancellationToken,
+    ) -> Result<BulkResult<ItemId, Listings>, client::GetError> {
+        get_many_chunked(
+            client,
+            "/v2/commerce/listings",
+            item_ids,
+            concurrency,
+            Some(cancel),
+        )
+        .await
     }
 }
 
 /// Definitions for the /v2/commerce/prices endpoint.
 /// See: https://wiki.guildwars2.com/wiki/API:2/commerce/prices
 pub mod prices {
-    use std::fmt::Write;
-
     use super::*;
+    use crate::coin::Coin;
 
     #[derive(thiserror::Error, Debug)]
     pub enum GetManyPricesError {
@@ -126,8 +564,8 @@ pub mod prices {
 
     #[derive(serde::Deserialize, Debug)]
     pub struct PriceInfo {
-        /// The highest buy order or lowest sell offer price in coins.
-        pub unit_price: u32,
+        /// The highest buy order or lowest sell offer price.
+        pub unit_price: Coin,
         /// The amount of items being bought or sold at this price level.
         pub quantity: u32,
     }
@@ -149,52 +587,136 @@ pub mod prices {
     /// Fetches all item IDs that have price information on the trading post.
     /// Corresponds to GET /v2/commerce/prices
     pub async fn get_all_ids(client: &Client) -> Result<Vec<ItemId>, client::GetError> {
-        Ok(client.get(&build_url("/v2/commerce/prices")).await?)
+        client.get(&client.url("/v2/commerce/prices")).await
     }
 
     /// Fetches all items that have price information on the trading post.
     pub async fn get_all(client: &Client) -> Result<Vec<Price>, client::PaginatedGetError> {
-        Ok(client
-            .get_all_pages(&build_url("/v2/commerce/prices"), Default::default())
-            .await?)
+        client
+            .get_all_pages(&client.url("/v2/commerce/prices"), Default::default())
+            .await
+    }
+
+    impl Identified for Price {
+        type Id = ItemId;
+
+        fn id(&self) -> &ItemId {
+            &self.id
+        }
     }
 
     /// Fetches the aggregated price information for a single item ID.
     /// Corresponds to GET /v2/commerce/prices/{id}
     pub async fn get_price(client: &Client, id: &ItemId) -> Result<Price, client::GetError> {
         client
-            .get(&build_url(&format!("/v2/commerce/prices/{}", id)))
+            .get(&client.url(&format!("/v2/commerce/prices/{}", id)))
             .await
     }
 
-    /// Fetches the aggregated price information for multiple item IDs.
+    /// Fetches the aggregated price information for multiple item IDs, in the order of
+    /// `ids`.
     /// Corresponds to GET /v2/commerce/prices?ids=...
-    /// Note: The API limits the number of IDs per request to 200.
+    /// Note: The API limits the number of distinct IDs per request to 200.
     pub async fn get_many_prices(
         client: &Client,
         ids: &[ItemId],
     ) -> Result<Vec<Price>, GetManyPricesError> {
-        if ids.len() > 200 {
-            return Err(GetManyPricesError::TooManyItemIds(ids.len()));
+        let normalized = normalize_ids(ids);
+        if normalized.len() > MAX_IDS_PER_REQUEST {
+            return Err(GetManyPricesError::TooManyItemIds(normalized.len()));
         }
 
-        if ids.is_empty() {
+        if normalized.is_empty() {
             return Ok(Vec::new());
         }
 
-        let param = ids.iter().fold(String::new(), |mut acc, id| {
-            if !acc.is_empty() {
-                acc.push(',');
-            }
+        let prices = client
+            .get(&client.url(&format!(
+                "/v2/commerce/prices?ids={}",
+                join_ids(&normalized)
+            )))
+            .await?;
+        Ok(in_request_order(ids, prices))
+    }
+
+    /// Fetches the aggregated price information for any number of item IDs, split into
+    /// requests of at most 200 IDs. IDs without prices are reported as missing.
+    /// Corresponds to GET /v2/commerce/prices?ids=...
+    pub async fn get_prices_chunked(
+        client: &Client,
+        ids: &[ItemId],
+    ) -> Result<BulkResult<ItemId, Price>, client::GetError> {
+        get_many_chunked(client, "/v2/commerce/prices", ids, 1, None).await
+    }
+
+    /// Like [`get_prices_chunked`], but with up to `concurrency` requests in flight at
+    /// once. Results keep the order of `ids`.
+    /// Corresponds to GET /v2/commerce/prices?ids=...
+    pub async fn get_prices_parallel(
+        client: &Client,
+        ids: &[ItemId],
+        concurrency: usize,
+    ) -> Result<BulkResult<ItemId, Price>, client::GetError> {
+        get_many_chunked(client, "/v2/commerce/prices", ids, concurrency, None).await
+    }
+
+    /// Like [`get_prices_parallel`], but stops once `cancel` is cancelled. IDs that were
+    /// never fetched are reported in `unfetched`, alongside the prices fetched until then.
+    /// Corresponds to GET /v2/commerce/prices?ids=...
+    pub async fn get_prices_cancellable(
+        client: &Client,
+        ids: &[ItemId],
+        concurrency: usize,
+        cancel: &client::CancellationToken,
+    ) -> Result<BulkResult<ItemId, Price>, client::GetError> {
+        get_many_chunked(
+            client,
+            "/v2/commerce/prices",
+            ids,
+            concurrency,
+            Some(cancel),
+        )
+        .await
+    }
+}
 
-            write!(&mut acc, "{}", id).expect("writing ItemId to String should not fail");
+/// Definitions for the /v2/commerce/exchange endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/commerce/exchange
+pub mod exchange {
+    use super::{client, Client};
+    use crate::coin::Coin;
+
+    #[derive(serde::Deserialize, Debug, Clone, Copy)]
+    pub struct Exchange {
+        /// The exchange rate at the quantity exchanged.
+        pub coins_per_gem: Coin,
+        /// What the exchange yields: gems when exchanging coins, copper when exchanging gems.
+        pub quantity: u64,
+    }
 
-            acc
-        });
+    /// Fetches how many gems `coins` buy.
+    /// Corresponds to GET /v2/commerce/exchange/coins?quantity={coins}
+    pub async fn get_coins_to_gems(
+        client: &Client,
+        coins: Coin,
+    ) -> Result<Exchange, client::GetError> {
+        client
+            .get(&client.url(&format!(
+                "/v2/commerce/exchange/coins?quantity={}",
+                coins.copper()
+            )))
+            .await
+    }
 
-        Ok(client
-            .get(&build_url(&format!("/v2/commerce/prices?ids={}", param)))
-            .await?)
+    /// Fetches how many coins `gems` sell for.
+    /// Corresponds to GET /v2/commerce/exchange/gems?quantity={gems}
+    pub async fn get_gems_to_coins(
+        client: &Client,
+        gems: u32,
+    ) -> Result<Exchange, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/commerce/exchange/gems?quantity={}", gems)))
+            .await
     }
 }
 
@@ -204,7 +726,8 @@ pub mod prices {
 /// Note: These endpoints are paginated by the API. These functions currently fetch only the first page.
 /// See: https://wiki.guildwars2.com/wiki/API:2/commerce/transactions
 pub mod transactions {
-    use super::{build_url, client, Client, ItemId};
+    use super::{client, Client, ItemId};
+    use crate::coin::Coin;
 
     #[derive(serde::Deserialize, Debug)]
     pub struct Transaction {
@@ -212,8 +735,8 @@ pub mod transactions {
         pub id: u64,
         /// The item id involved in the transaction.
         pub item_id: ItemId,
-        /// The price of the item in coins (per item).
-        pub price: u32,
+        /// The price of the item (per item).
+        pub price: Coin,
         /// The quantity of the item in the transaction.
         pub quantity: u32,
         /// The date the transaction was created (ISO-8601 format string).
@@ -234,7 +757,7 @@ pub mod transactions {
     ) -> Result<Vec<Transaction>, client::PaginatedGetError> {
         client
             .get_all_pages(
-                &build_url("/v2/commerce/transactions/current/buys"),
+                &client.url("/v2/commerce/transactions/current/buys"),
                 Default::default(),
             )
             .await
@@ -249,7 +772,7 @@ pub mod transactions {
     ) -> Result<Vec<Transaction>, client::PaginatedGetError> {
         client
             .get_all_pages(
-                &build_url("/v2/commerce/transactions/current/sells"),
+                &client.url("/v2/commerce/transactions/current/sells"),
                 Default::default(),
             )
             .await
@@ -264,7 +787,7 @@ pub mod transactions {
     ) -> Result<Vec<Transaction>, client::PaginatedGetError> {
         client
             .get_all_pages(
-                &build_url("/v2/commerce/transactions/history/buys"),
+                &client.url("/v2/commerce/transactions/history/buys"),
                 Default::default(),
             )
             .await
@@ -279,9 +802,132 @@ pub mod transactions {
     ) -> Result<Vec<Transaction>, client::PaginatedGetError> {
         client
             .get_all_pages(
-                &build_url("/v2/commerce/transactions/history/sells"),
+                &client.url("/v2/commerce/transactions/history/sells"),
                 Default::default(),
             )
             .await
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::transport::{Request, Response, Transport, TransportFuture};
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Entry {
+        id: u32,
+    }
+
+    impl Identified for Entry {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Echoes the requested ids except multiples of 7, answering earlier chunks more slowly.
+    #[derive(Debug)]
+    struct EchoIds;
+
+    impl Transport for EchoIds {
+        fn send(&self, request: Request) -> TransportFuture<'_> {
+            Box::pin(async move {
+                let ids: Vec<u32> = request
+                    .url
+                    .split("ids=")
+                    .nth(1)
+                    .unwrap()
+                    .split(',')
+                    .map(|id| id.parse().unwrap())
+                    .collect();
+                let delay = 1000u64.saturating_sub(ids[0] as u64) / 100;
+                tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
+
+                Ok(Response {
+                    status: reqwest::StatusCode::OK,
+                    headers: Default::default(),
+                    body: serde_json::to_vec(
+                        &ids.iter()
+                            .filter(|id| *id % 7 != 0)
+                            .map(|id| serde_json::json!({ "id": id }))
+                            .collect::<Vec<_>>(),
+                    )
+                    .unwrap()
+                    .into(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn parallel_chunks_keep_input_order_and_report_missing() {
+        let client = Client::builder().transport(EchoIds).build().unwrap();
+        let ids: Vec<u32> = (0..1000).collect();
+
+        let results: BulkResult<u32, Entry> = get_many_chunked(&client, "/v2/test", &ids, 4, None)
+            .await
+            .unwrap();
+
+        let found: Vec<u32> = results.found.iter().map(|entry| entry.id).collect();
+        assert_eq!(
+            found,
+            ids.iter()
+                .copied()
+                .filter(|id| id % 7 != 0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            results.missing,
+            ids.iter()
+                .copied()
+                .filter(|id| id % 7 == 0)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(client.metrics().requests(), 5);
+    }
+
+    #[tokio::test]
+    async fn cancelled_chunks_are_reported_as_unfetched() {
+        let client = Client::builder().transport(EchoIds).build().unwrap();
+        let ids: Vec<u32> = (0..1000).collect();
+        let cancel = client::CancellationToken::new();
+        cancel.cancel();
+
+        let results: BulkResult<u32, Entry> =
+            get_many_chunked(&client, "/v2/test", &ids, 4, Some(&cancel))
+                .await
+                .unwrap();
+
+        assert!(results.found.is_empty());
+        assert!(results.is_cancelled());
+        assert_eq!(results.unfetched, ids);
+        assert_eq!(client.metrics().requests(), 0);
+    }
+
+    #[tokio::test]
+    async fn ids_are_normalized_and_mapped_back() {
+        let client = Client::builder().transport(EchoIds).build().unwrap();
+
+        let results: BulkResult<u32, Entry> =
+            get_many_chunked(&client, "/v2/test", &[5, 3, 14, 5, 1], 1, None)
+                .await
+                .unwrap();
+        let found: Vec<u32> = results.found.iter().map(|entry| entry.id).collect();
+        assert_eq!(found, vec![5, 3, 1]);
+        assert_eq!(results.missing, vec![14]);
+
+        let entries: Vec<Entry> = get_many(&client, "/v2/test", &[5, 3, 14, 5, 1])
+            .await
+            .unwrap();
+        let found: Vec<u32> = entries.iter().map(|entry| entry.id).collect();
+        assert_eq!(found, vec![5, 3, 1]);
+
+        assert_eq!(normalize_ids(&[5, 3, 14, 5, 1]), vec![1, 3, 5, 14]);
+        assert_eq!(
+            in_request_order(&[5, 3, 1, 3], vec![Entry { id: 1 }, Entry { id: 5 }]),
+            vec![Entry { id: 5 }, Entry { id: 1 }]
+        );
+    }
+}
@@ -0,0 +1,253 @@
+use super::{client, Client, ItemId};
+
+/// Fetches the raid event ids the account has cleared since the weekly reset.
+/// Corresponds to GET /v2/account/raids
+/// Requires authentication: 'account', 'progression' scopes.
+pub async fn get_raids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/account/raids")).await
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct FinisherUnlock {
+    /// The finisher id, see `finishers`.
+    pub id: u32,
+    /// Whether the finisher is unlocked permanently.
+    pub permanent: bool,
+    /// The number of uses left, for finishers that are not permanent.
+    pub quantity: Option<u32>,
+}
+
+/// Fetches the finishers unlocked by the account.
+/// Corresponds to GET /v2/account/finishers
+/// Requires authentication: 'account', 'unlocks' scopes.
+pub async fn get_finishers(client: &Client) -> Result<Vec<FinisherUnlock>, client::GetError> {
+    client.get(&client.url("/v2/account/finishers")).await
+}
+
+/// Fetches the ids of the emotes unlocked by the account, see `emotes`.
+/// Corresponds to GET /v2/account/emotes
+/// Requires authentication: 'account', 'unlocks' scopes.
+pub async fn get_emotes(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/account/emotes")).await
+}
+
+/// Fetches the ids of the jade bot skins unlocked by the account, see `jadebots`.
+/// Corresponds to GET /v2/account/jadebots
+/// Requires authentication: 'account', 'unlocks' scopes.
+pub async fn get_jadebots(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/account/jadebots")).await
+}
+
+/// Fetches the ids of the skiff skins unlocked by the account, see `skiffs`.
+/// Corresponds to GET /v2/account/skiffs
+/// Requires authentication: 'account', 'unlocks' scopes.
+pub async fn get_skiffs(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/account/skiffs")).await
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct WalletCurrency {
+    /// The currency id, see /v2/currencies. Coins are id 1.
+    pub id: u32,
+    /// The amount of the currency held.
+    pub value: u64,
+}
+
+/// Fetches the currencies held in the account's wallet.
+/// Corresponds to GET /v2/account/wallet
+/// Requires authentication: 'account', 'wallet' scopes.
+pub async fn get_wallet(client: &Client) -> Result<Vec<WalletCurrency>, client::GetError> {
+    client.get(&client.url("/v2/account/wallet")).await
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct BankSlot {
+    /// The item id.
+    pub id: ItemId,
+    /// The number of items in the stack.
+    pub count: u32,
+    /// The number of charges left, for items with charges.
+    pub charges: Option<u32>,
+    /// The skin applied to the item, if any.
+    pub skin: Option<u32>,
+    /// The upgrade item ids in the item, if any.
+    #[serde(default)]
+    pub upgrades: Vec<u32>,
+    /// The infusion item ids in the item, if any.
+    #[serde(default)]
+    pub infusions: Vec<u32>,
+    /// "Account" or "Character", if the item is bound.
+    pub binding: Option<String>,
+    /// The character the item is bound to, for character bound items.
+    pub bound_to: Option<String>,
+}
+
+/// Fetches the account's bank, one entry per slot. Empty slots are `None`.
+/// Corresponds to GET /v2/account/bank
+/// Requires authentication: 'account', 'inventories' scopes.
+pub async fn get_bank(client: &Client) -> Result<Vec<Option<BankSlot>>, client::GetError> {
+    client.get(&client.url("/v2/account/bank")).await
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MaterialSlot {
+    /// The item id.
+    pub id: ItemId,
+    /// The material storage category the item belongs to.
+    pub category: u32,
+    /// The number of items stored.
+    pub count: u32,
+    /// "Account" if the material is bound.
+    pub binding: Option<String>,
+}
+
+/// Fetches the account's material storage.
+/// Corresponds to GET /v2/account/materials
+/// Requires authentication: 'account', 'inventories' scopes.
+pub async fn get_materials(client: &Client) -> Result<Vec<MaterialSlot>, client::GetError> {
+    client.get(&client.url("/v2/account/materials")).await
+}
+
+/// Definitions for the /v2/account/wizardsvault endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/account/wizardsvault
+pub mod wizardsvault {
+    use crate::api::wizardsvault::{ListingKind, Track};
+    use crate::api::{client, Client, ItemId};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct ObjectiveProgress {
+        /// The objective id, see `wizardsvault::objectives`.
+        pub id: u32,
+        /// The localized title of the objective.
+        pub title: String,
+        /// The game mode the objective belongs to.
+        pub track: Track,
+        /// The Astral Acclaim rewarded for completing the objective.
+        pub acclaim: u32,
+        /// The current progress towards the objective.
+        pub progress_current: u32,
+        /// The progress required to complete the objective.
+        pub progress_complete: u32,
+        /// Whether the reward has been claimed.
+        pub claimed: bool,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct PeriodProgress {
+        /// The number of objectives completed towards the meta reward.
+        pub meta_progress_current: u32,
+        /// The number of objectives required for the meta reward.
+        pub meta_progress_complete: u32,
+        /// The item granted by the meta reward.
+        pub meta_reward_item_id: ItemId,
+        /// The Astral Acclaim granted by the meta reward.
+        pub meta_reward_astral: u32,
+        /// Whether the meta reward has been claimed.
+        pub meta_reward_claimed: bool,
+        /// The objectives of the period.
+        pub objectives: Vec<ObjectiveProgress>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct SpecialProgress {
+        /// The special objectives of the season.
+        pub objectives: Vec<ObjectiveProgress>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct AccountListing {
+        /// The listing id, see `wizardsvault::listings`.
+        pub id: u32,
+        /// The item granted by the listing.
+        pub item_id: ItemId,
+        /// The number of items granted per purchase.
+        pub item_count: u32,
+        /// Which section of the vault the listing is shown in.
+        #[serde(rename = "type")]
+        pub kind: ListingKind,
+        /// The cost of a purchase in Astral Acclaim.
+        pub cost: u32,
+        /// The number of times the account has purchased the listing.
+        pub purchased: Option<u32>,
+        /// The maximum number of purchases allowed. Missing for unlimited listings.
+        pub purchase_limit: Option<u32>,
+    }
+
+    impl AccountListing {
+        /// The number of purchases left, or `None` if the listing is unlimited.
+        pub fn remaining_purchases(&self) -> Option<u32> {
+            let limit = self.purchase_limit?;
+            Some(limit.saturating_sub(self.purchased.unwrap_or(0)))
+        }
+    }
+
+    /// Fetches the account's progress on the daily Wizard's Vault objectives.
+    /// Corresponds to GET /v2/account/wizardsvault/daily
+    /// Requires authentication: 'account' scope.
+    pub async fn get_daily(client: &Client) -> Result<PeriodProgress, client::GetError> {
+        client
+            .get(&client.url("/v2/account/wizardsvault/daily"))
+            .await
+    }
+
+    /// Fetches the account's progress on the weekly Wizard's Vault objectives.
+    /// Corresponds to GET /v2/account/wizardsvault/weekly
+    /// Requires authentication: 'account' scope.
+    pub async fn get_weekly(client: &Client) -> Result<PeriodProgress, client::GetError> {
+        client
+            .get(&client.url("/v2/account/wizardsvault/weekly"))
+            .await
+    }
+
+    /// Fetches the account's progress on the special Wizard's Vault objectives.
+    /// Corresponds to GET /v2/account/wizardsvault/special
+    /// Requires authentication: 'account' scope.
+    pub async fn get_special(client: &Client) -> Result<SpecialProgress, client::GetError> {
+        client
+            .get(&client.url("/v2/account/wizardsvault/special"))
+            .await
+    }
+
+    /// Fetches the Wizard's Vault listings along with the account's purchase counts.
+    /// Corresponds to GET /v2/account/wizardsvault/listings
+    /// Requires authentication: 'account' scope.
+    pub async fn get_listings(client: &Client) -> Result<Vec<AccountListing>, client::GetError> {
+        client
+            .get(&client.url("/v2/account/wizardsvault/listings"))
+            .await
+    }
+}
+
+/// Definitions for the /v2/account/homestead endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/account/homestead
+pub mod homestead {
+    use crate::api::{client, Client};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct StoredDecoration {
+        /// The decoration id, see `homestead::decorations`.
+        pub id: u32,
+        /// The number of the decoration in storage.
+        pub count: u32,
+    }
+
+    /// Fetches the homestead decorations the account has in storage.
+    /// Corresponds to GET /v2/account/homestead/decorations
+    /// Requires authentication: 'account', 'unlocks' scopes.
+    pub async fn get_decorations(
+        client: &Client,
+    ) -> Result<Vec<StoredDecoration>, client::GetError> {
+        client
+            .get(&client.url("/v2/account/homestead/decorations"))
+            .await
+    }
+
+    /// Fetches the ids of the homestead glyphs unlocked by the account, see `homestead::glyphs`.
+    /// Corresponds to GET /v2/account/homestead/glyphs
+    /// Requires authentication: 'account', 'unlocks' scopes.
+    pub async fn get_glyphs(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client
+            .get(&client.url("/v2/account/homestead/glyphs"))
+            .await
+    }
+}
@@ -0,0 +1,127 @@
+/// Definitions for the /v2/backstory/questions endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/backstory/questions
+pub mod questions {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Question {
+        /// The question id.
+        pub id: u32,
+        /// The localized title of the question.
+        pub title: String,
+        /// The localized description of the question.
+        pub description: String,
+        /// The answer ids of the question, see `backstory::answers`.
+        pub answers: Vec<String>,
+        /// The order the question is asked in during character creation.
+        pub order: u32,
+        /// The races the question is asked to. Missing if asked to every race.
+        pub races: Option<Vec<String>>,
+        /// The professions the question is asked to. Missing if asked to every profession.
+        pub professions: Option<Vec<String>>,
+    }
+
+    impl Identified for Question {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all backstory question ids.
+    /// Corresponds to GET /v2/backstory/questions
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/backstory/questions")).await
+    }
+
+    /// Fetches all questions.
+    /// Corresponds to GET /v2/backstory/questions?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Question>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/backstory/questions"))
+            .await
+    }
+
+    /// Fetches a single backstory question.
+    /// Corresponds to GET /v2/backstory/questions/{id}
+    pub async fn get_question(client: &Client, id: u32) -> Result<Question, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/backstory/questions/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple questions.
+    /// Corresponds to GET /v2/backstory/questions?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_questions(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<Question>, GetManyError> {
+        get_many(client, "/v2/backstory/questions", ids).await
+    }
+}
+
+/// Definitions for the /v2/backstory/answers endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/backstory/answers
+pub mod answers {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Answer {
+        /// The answer id (e.g. "7-54"), as reported by `characters::get_backstory`.
+        pub id: String,
+        /// The localized title of the answer.
+        pub title: String,
+        /// The localized description of the answer.
+        pub description: String,
+        /// The localized journal entry written for the answer.
+        pub journal: String,
+        /// The question the answer belongs to, see `backstory::questions`.
+        pub question: u32,
+        /// The races the answer is available to. Missing if available to every race.
+        pub races: Option<Vec<String>>,
+        /// The professions the answer is available to. Missing if available to every profession.
+        pub professions: Option<Vec<String>>,
+    }
+
+    impl Identified for Answer {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches all backstory answer ids.
+    /// Corresponds to GET /v2/backstory/answers
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/backstory/answers")).await
+    }
+
+    /// Fetches all answers.
+    /// Corresponds to GET /v2/backstory/answers?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Answer>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/backstory/answers"))
+            .await
+    }
+
+    /// Fetches a single backstory answer.
+    /// Corresponds to GET /v2/backstory/answers/{id}
+    pub async fn get_answer(client: &Client, id: &str) -> Result<Answer, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/backstory/answers/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple answers.
+    /// Corresponds to GET /v2/backstory/answers?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_answers(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Answer>, GetManyError> {
+        get_many(client, "/v2/backstory/answers", ids).await
+    }
+}
@@ -0,0 +1,14 @@
+use super::{client, Client};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Build {
+    /// The current game build id.
+    pub id: u32,
+}
+
+/// Fetches the id of the current game build.
+/// Corresponds to GET /v2/build
+pub async fn get_build_id(client: &Client) -> Result<u32, client::GetError> {
+    let build: Build = client.get(&client.url("/v2/build")).await?;
+    Ok(build.id)
+}
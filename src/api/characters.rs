@@ -0,0 +1,48 @@
+use super::{client, Client};
+
+/// Builds the url of a character sub-resource, escaping the character name.
+fn character_url(client: &Client, name: &str, resource: &str) -> String {
+    let mut url =
+        reqwest::Url::parse(&client.url("/v2/characters")).expect("characters url should be valid");
+
+    url.path_segments_mut()
+        .expect("characters url should have a path")
+        .push(name)
+        .push(resource);
+
+    url.into()
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Backstory {
+    /// The backstory answer ids chosen by the character, see `backstory::answers`.
+    pub backstory: Vec<String>,
+}
+
+/// Fetches the backstory answers a character picked during character creation.
+/// Corresponds to GET /v2/characters/{name}/backstory
+/// Requires authentication: 'account', 'characters' scopes.
+pub async fn get_backstory(client: &Client, name: &str) -> Result<Backstory, client::GetError> {
+    client.get(&character_url(client, name, "backstory")).await
+}
+
+/// Fetches the ids of the story quests a character has completed, see `quests`.
+/// Corresponds to GET /v2/characters/{name}/quests
+/// Requires authentication: 'account', 'characters', 'progression' scopes.
+pub async fn get_quests(client: &Client, name: &str) -> Result<Vec<u32>, client::GetError> {
+    client.get(&character_url(client, name, "quests")).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn character_url_escapes_name() {
+        let client = Client::new(None).unwrap();
+        assert_eq!(
+            character_url(&client, "Eir Stegalkin", "backstory"),
+            client.url("/v2/characters/Eir%20Stegalkin/backstory")
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug, Clone, Copy)]
+pub struct ColorMaterial {
+    pub brightness: i32,
+    pub contrast: f64,
+    pub hue: i32,
+    pub saturation: f64,
+    pub lightness: f64,
+    /// The resulting color on this material.
+    pub rgb: [u8; 3],
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Color {
+    /// The color id.
+    pub id: u32,
+    /// The localized name of the dye.
+    pub name: String,
+    /// The base color the material adjustments are applied to.
+    pub base_rgb: [u8; 3],
+    /// How the dye looks on cloth armor.
+    pub cloth: ColorMaterial,
+    /// How the dye looks on leather armor.
+    pub leather: ColorMaterial,
+    /// How the dye looks on metal armor.
+    pub metal: ColorMaterial,
+    /// How the dye looks on fur, if it differs from the other materials.
+    pub fur: Option<ColorMaterial>,
+    /// The dye item that unlocks the color. Missing for colors unlocked by default.
+    pub item: Option<ItemId>,
+    /// Hue, material and rarity categories (e.g. "Blue", "Metal", "Rare").
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+impl Identified for Color {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all color ids.
+/// Corresponds to GET /v2/colors
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/colors")).await
+}
+
+/// Fetches all colors.
+/// Corresponds to GET /v2/colors?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Color>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/colors")).await
+}
+
+/// Fetches a single color.
+/// Corresponds to GET /v2/colors/{id}
+pub async fn get_color(client: &Client, id: u32) -> Result<Color, client::GetError> {
+    client.get(&client.url(&format!("/v2/colors/{}", id))).await
+}
+
+/// Fetches multiple colors.
+/// Corresponds to GET /v2/colors?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_colors(client: &Client, ids: &[u32]) -> Result<Vec<Color>, GetManyError> {
+    get_many(client, "/v2/colors", ids).await
+}
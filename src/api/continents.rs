@@ -0,0 +1,480 @@
+use std::collections::HashMap;
+
+use super::{client, get_many, Client, GetManyError, Identified};
+
+/// A 2D map coordinate.
+pub type Coord = [f64; 2];
+
+/// A rectangle given as its top-left and bottom-right coordinates.
+pub type Rect = [Coord; 2];
+
+/// Path to a floor of a continent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FloorPath {
+    pub continent_id: u32,
+    pub floor_id: i32,
+}
+
+/// Path to a region of a continent floor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RegionPath {
+    pub floor: FloorPath,
+    pub region_id: u32,
+}
+
+/// Path to a map of a continent floor region.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapPath {
+    pub region: RegionPath,
+    pub map_id: u32,
+}
+
+impl FloorPath {
+    pub fn new(continent_id: u32, floor_id: i32) -> Self {
+        Self {
+            continent_id,
+            floor_id,
+        }
+    }
+
+    /// Descends into a region of this floor.
+    pub fn region(self, region_id: u32) -> RegionPath {
+        RegionPath {
+            floor: self,
+            region_id,
+        }
+    }
+
+    /// The endpoint of this floor (without the API domain).
+    pub fn endpoint(&self) -> String {
+        format!(
+            "/v2/continents/{}/floors/{}",
+            self.continent_id, self.floor_id
+        )
+    }
+}
+
+impl RegionPath {
+    /// Descends into a map of this region.
+    pub fn map(self, map_id: u32) -> MapPath {
+        MapPath {
+            region: self,
+            map_id,
+        }
+    }
+
+    /// The endpoint of this region (without the API domain).
+    pub fn endpoint(&self) -> String {
+        format!("{}/regions/{}", self.floor.endpoint(), self.region_id)
+    }
+}
+
+impl MapPath {
+    /// The endpoint of this map (without the API domain).
+    pub fn endpoint(&self) -> String {
+        format!("{}/maps/{}", self.region.endpoint(), self.map_id)
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Continent {
+    /// The continent id.
+    pub id: u32,
+    /// The localized name of the continent.
+    pub name: String,
+    /// The width and height of the continent.
+    pub continent_dims: [u32; 2],
+    /// The minimal zoom level of the continent's tiles.
+    pub min_zoom: u32,
+    /// The maximal zoom level of the continent's tiles.
+    pub max_zoom: u32,
+    /// The floor ids available on the continent.
+    pub floors: Vec<i32>,
+}
+
+impl Identified for Continent {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Floor {
+    /// The floor id.
+    pub id: i32,
+    /// The width and height of the floor's texture.
+    pub texture_dims: [u32; 2],
+    /// The rectangle of the floor that contains content, if it doesn't cover the whole texture.
+    pub clamped_view: Option<Rect>,
+    /// The regions on the floor keyed by region id.
+    pub regions: HashMap<u32, Region>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Region {
+    /// The region id.
+    pub id: u32,
+    /// The localized name of the region.
+    pub name: String,
+    /// The coordinates of the region's label.
+    pub label_coord: Coord,
+    /// The rectangle the region covers on the continent.
+    pub continent_rect: Rect,
+    /// The maps in the region keyed by map id.
+    pub maps: HashMap<u32, Map>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Map {
+    /// The map id, see `maps`.
+    pub id: u32,
+    /// The localized name of the map.
+    pub name: String,
+    /// The minimal level of the map.
+    pub min_level: u32,
+    /// The maximal level of the map.
+    pub max_level: u32,
+    /// The default floor of the map.
+    pub default_floor: i32,
+    /// The coordinates of the map's label.
+    pub label_coord: Option<Coord>,
+    /// The rectangle the map covers in its own coordinate system.
+    pub map_rect: Rect,
+    /// The rectangle the map covers on the continent.
+    pub continent_rect: Rect,
+    /// The points of interest on the map keyed by id.
+    pub points_of_interest: HashMap<u32, PointOfInterest>,
+    /// The renown hearts on the map keyed by id.
+    pub tasks: HashMap<u32, Task>,
+    /// The hero challenges on the map.
+    pub skill_challenges: Vec<SkillChallenge>,
+    /// The areas of the map keyed by sector id.
+    pub sectors: HashMap<u32, Sector>,
+    /// The adventures on the map.
+    #[serde(default)]
+    pub adventures: Vec<Adventure>,
+    /// The mastery insights on the map.
+    #[serde(default)]
+    pub mastery_points: Vec<MasteryPoint>,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum PointOfInterestKind {
+    Landmark,
+    Waypoint,
+    Vista,
+    Unlock,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct PointOfInterest {
+    /// The point of interest id.
+    pub id: u32,
+    /// The localized name of the point of interest.
+    pub name: Option<String>,
+    /// The kind of point of interest.
+    #[serde(rename = "type")]
+    pub kind: PointOfInterestKind,
+    /// The floor the point of interest is on.
+    pub floor: i32,
+    /// The coordinates of the point of interest.
+    pub coord: Coord,
+    /// The chat link of the point of interest.
+    pub chat_link: String,
+    /// The url of the point of interest's icon, for unlock points.
+    pub icon: Option<String>,
+}
+
+impl Identified for PointOfInterest {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Task {
+    /// The renown heart id.
+    pub id: u32,
+    /// The localized objective of the renown heart.
+    pub objective: String,
+    /// The level of the renown heart.
+    pub level: u32,
+    /// The coordinates of the renown heart.
+    pub coord: Coord,
+    /// The outline of the renown heart's area.
+    pub bounds: Vec<Coord>,
+    /// The chat link of the renown heart.
+    pub chat_link: String,
+}
+
+impl Identified for Task {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct SkillChallenge {
+    /// The hero challenge id (e.g. "0-4"), missing for some challenges.
+    pub id: Option<String>,
+    /// The coordinates of the hero challenge.
+    pub coord: Coord,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Sector {
+    /// The sector id.
+    pub id: u32,
+    /// The localized name of the sector.
+    pub name: Option<String>,
+    /// The level of the sector.
+    pub level: u32,
+    /// The coordinates of the sector's label.
+    pub coord: Coord,
+    /// The outline of the sector.
+    pub bounds: Vec<Coord>,
+    /// The chat link of the sector.
+    pub chat_link: String,
+}
+
+impl Identified for Sector {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Adventure {
+    /// The adventure id (a UUID string).
+    pub id: String,
+    /// The localized name of the adventure.
+    pub name: String,
+    /// The localized description of the adventure.
+    pub description: String,
+    /// The coordinates of the adventure.
+    pub coord: Coord,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MasteryPoint {
+    /// The mastery insight id.
+    pub id: u32,
+    /// The mastery region the insight belongs to.
+    pub region: String,
+    /// The coordinates of the mastery insight.
+    pub coord: Coord,
+}
+
+/// Fetches all continent ids.
+/// Corresponds to GET /v2/continents
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/continents")).await
+}
+
+/// Fetches a single continent.
+/// Corresponds to GET /v2/continents/{id}
+pub async fn get_continent(client: &Client, id: u32) -> Result<Continent, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/continents/{}", id)))
+        .await
+}
+
+/// Fetches multiple continents.
+/// Corresponds to GET /v2/continents?ids=...
+pub async fn get_many_continents(
+    client: &Client,
+    ids: &[u32],
+) -> Result<Vec<Continent>, GetManyError> {
+    get_many(client, "/v2/continents", ids).await
+}
+
+/// Fetches the floor ids of a continent.
+/// Corresponds to GET /v2/continents/{continent_id}/floors
+pub async fn get_floor_ids(
+    client: &Client,
+    continent_id: u32,
+) -> Result<Vec<i32>, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/continents/{}/floors", continent_id)))
+        .await
+}
+
+/// Fetches a floor including all of its regions, maps and their contents.
+/// Corresponds to GET /v2/continents/{continent_id}/floors/{floor_id}
+pub async fn get_floor(client: &Client, path: FloorPath) -> Result<Floor, client::GetError> {
+    client.get(&client.url(&path.endpoint())).await
+}
+
+/// Fetches the region ids of a floor.
+/// Corresponds to GET /v2/continents/{continent_id}/floors/{floor_id}/regions
+pub async fn get_region_ids(
+    client: &Client,
+    path: FloorPath,
+) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/regions", path.endpoint())))
+        .await
+}
+
+/// Fetches a region including all of its maps and their contents.
+/// Corresponds to GET /v2/continents/{continent_id}/floors/{floor_id}/regions/{region_id}
+pub async fn get_region(client: &Client, path: RegionPath) -> Result<Region, client::GetError> {
+    client.get(&client.url(&path.endpoint())).await
+}
+
+/// Fetches the map ids of a region.
+/// Corresponds to GET /v2/continents/{continent_id}/floors/{floor_id}/regions/{region_id}/maps
+pub async fn get_map_ids(client: &Client, path: RegionPath) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/maps", path.endpoint())))
+        .await
+}
+
+/// Fetches a map including its sectors, points of interest and tasks.
+/// Corresponds to GET /v2/continents/{continent_id}/floors/{floor_id}/regions/{region_id}/maps/{map_id}
+pub async fn get_map(client: &Client, path: MapPath) -> Result<Map, client::GetError> {
+    client.get(&client.url(&path.endpoint())).await
+}
+
+/// Fetches the sector ids of a map.
+/// Corresponds to GET .../maps/{map_id}/sectors
+pub async fn get_sector_ids(client: &Client, path: MapPath) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/sectors", path.endpoint())))
+        .await
+}
+
+/// Fetches a single sector of a map.
+/// Corresponds to GET .../maps/{map_id}/sectors/{id}
+pub async fn get_sector(
+    client: &Client,
+    path: MapPath,
+    id: u32,
+) -> Result<Sector, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/sectors/{}", path.endpoint(), id)))
+        .await
+}
+
+/// Fetches multiple sectors of a map.
+/// Corresponds to GET .../maps/{map_id}/sectors?ids=...
+pub async fn get_many_sectors(
+    client: &Client,
+    path: MapPath,
+    ids: &[u32],
+) -> Result<Vec<Sector>, GetManyError> {
+    get_many(client, &format!("{}/sectors", path.endpoint()), ids).await
+}
+
+/// Fetches the point of interest ids of a map.
+/// Corresponds to GET .../maps/{map_id}/pois
+pub async fn get_poi_ids(client: &Client, path: MapPath) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/pois", path.endpoint())))
+        .await
+}
+
+/// Fetches a single point of interest of a map.
+/// Corresponds to GET .../maps/{map_id}/pois/{id}
+pub async fn get_poi(
+    client: &Client,
+    path: MapPath,
+    id: u32,
+) -> Result<PointOfInterest, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/pois/{}", path.endpoint(), id)))
+        .await
+}
+
+/// Fetches multiple points of interest of a map.
+/// Corresponds to GET .../maps/{map_id}/pois?ids=...
+pub async fn get_many_pois(
+    client: &Client,
+    path: MapPath,
+    ids: &[u32],
+) -> Result<Vec<PointOfInterest>, GetManyError> {
+    get_many(client, &format!("{}/pois", path.endpoint()), ids).await
+}
+
+/// Fetches the renown heart ids of a map.
+/// Corresponds to GET .../maps/{map_id}/tasks
+pub async fn get_task_ids(client: &Client, path: MapPath) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/tasks", path.endpoint())))
+        .await
+}
+
+/// Fetches a single renown heart of a map.
+/// Corresponds to GET .../maps/{map_id}/tasks/{id}
+pub async fn get_task(client: &Client, path: MapPath, id: u32) -> Result<Task, client::GetError> {
+    client
+        .get(&client.url(&format!("{}/tasks/{}", path.endpoint(), id)))
+        .await
+}
+
+/// Fetches multiple renown hearts of a map.
+/// Corresponds to GET .../maps/{map_id}/tasks?ids=...
+pub async fn get_many_tasks(
+    client: &Client,
+    path: MapPath,
+    ids: &[u32],
+) -> Result<Vec<Task>, GetManyError> {
+    get_many(client, &format!("{}/tasks", path.endpoint()), ids).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nested_paths_build_endpoints() {
+        let floor = FloorPath::new(1, -2);
+        let map = floor.region(4).map(15);
+
+        assert_eq!(floor.endpoint(), "/v2/continents/1/floors/-2");
+        assert_eq!(
+            map.endpoint(),
+            "/v2/continents/1/floors/-2/regions/4/maps/15"
+        );
+    }
+
+    #[test]
+    fn region_deserializes_numeric_keys() {
+        let region: Region = serde_json::from_str(
+            r#"{
+                "id": 4,
+                "name": "Kryta",
+                "label_coord": [10.0, 20.0],
+                "continent_rect": [[0.0, 0.0], [100.0, 100.0]],
+                "maps": {
+                    "15": {
+                        "id": 15,
+                        "name": "Queensdale",
+                        "min_level": 1,
+                        "max_level": 15,
+                        "default_floor": 1,
+                        "map_rect": [[0.0, 0.0], [10.0, 10.0]],
+                        "continent_rect": [[0.0, 0.0], [10.0, 10.0]],
+                        "points_of_interest": {},
+                        "tasks": {},
+                        "skill_challenges": [],
+                        "sectors": {}
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(region.id, 4);
+        assert_eq!(region.maps[&15].name, "Queensdale");
+    }
+}
@@ -0,0 +1,45 @@
+use super::{client, tokeninfo::Permission, Client};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Subtoken {
+    /// The created subtoken, usable like an API key until it expires.
+    pub subtoken: String,
+}
+
+/// Builds the url creating a subtoken expiring at `expire` (ISO-8601 timestamp), restricted
+/// to `permissions` and, if not empty, to `urls`.
+pub(crate) fn subtoken_url(
+    client: &Client,
+    expire: &str,
+    permissions: &[Permission],
+    urls: &[&str],
+) -> String {
+    let mut params = vec![("expire", expire.to_string())];
+    if !permissions.is_empty() {
+        let permissions: Vec<&str> = permissions.iter().map(Permission::name).collect();
+        params.push(("permissions", permissions.join(",")));
+    }
+    if !urls.is_empty() {
+        params.push(("urls", urls.join(",")));
+    }
+
+    reqwest::Url::parse_with_params(&client.url("/v2/createsubtoken"), &params)
+        .expect("createsubtoken url should be valid")
+        .into()
+}
+
+/// Creates a subtoken of the client's API key, expiring at `expire` (ISO-8601 timestamp,
+/// e.g. `2025-01-01T00:00:00Z`). The subtoken is restricted to `permissions`, which must be
+/// granted by the key, and to `urls` (e.g. `/v2/account`) unless empty.
+/// Corresponds to GET /v2/createsubtoken
+/// Requires authentication.
+pub async fn create_subtoken(
+    client: &Client,
+    expire: &str,
+    permissions: &[Permission],
+    urls: &[&str],
+) -> Result<Subtoken, client::GetError> {
+    client
+        .get(&subtoken_url(client, expire, permissions, urls))
+        .await
+}
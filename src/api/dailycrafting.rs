@@ -0,0 +1,7 @@
+use super::{client, Client};
+
+/// Fetches the ids of all time-gated daily crafts (e.g. "charged_quartz_crystal").
+/// Corresponds to GET /v2/dailycrafting
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/dailycrafting")).await
+}
@@ -0,0 +1,62 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PathKind {
+    Story,
+    Explorable,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct DungeonPath {
+    /// The path id, as reported by the account dungeons endpoint.
+    pub id: String,
+    /// Whether the path is the story mode or an explorable path.
+    #[serde(rename = "type")]
+    pub kind: PathKind,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Dungeon {
+    /// The dungeon id (e.g. "ascalonian_catacombs").
+    pub id: String,
+    /// The paths of the dungeon.
+    pub paths: Vec<DungeonPath>,
+}
+
+impl Identified for Dungeon {
+    type Id = String;
+
+    fn id(&self) -> &String {
+        &self.id
+    }
+}
+
+/// Fetches all dungeon ids.
+/// Corresponds to GET /v2/dungeons
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/dungeons")).await
+}
+
+/// Fetches all dungeons.
+/// Corresponds to GET /v2/dungeons?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Dungeon>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/dungeons")).await
+}
+
+/// Fetches a single dungeon.
+/// Corresponds to GET /v2/dungeons/{id}
+pub async fn get_dungeon(client: &Client, id: &str) -> Result<Dungeon, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/dungeons/{}", id)))
+        .await
+}
+
+/// Fetches multiple dungeons.
+/// Corresponds to GET /v2/dungeons?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_dungeons(
+    client: &Client,
+    ids: &[String],
+) -> Result<Vec<Dungeon>, GetManyError> {
+    get_many(client, "/v2/dungeons", ids).await
+}
@@ -0,0 +1,95 @@
+use super::Identified;
+
+#[derive(serde::Deserialize, Debug)]
+pub struct EmblemLayer {
+    /// The emblem layer id, as referenced by `guild` emblems.
+    pub id: u32,
+    /// The urls of the images making up the layer, to be stacked in order.
+    pub layers: Vec<String>,
+}
+
+impl Identified for EmblemLayer {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Definitions for the /v2/emblem/foregrounds endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/emblem
+pub mod foregrounds {
+    use super::EmblemLayer;
+    use crate::api::{client, get_many, Client, GetManyError};
+
+    /// Fetches all emblem foreground ids.
+    /// Corresponds to GET /v2/emblem/foregrounds
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/emblem/foregrounds")).await
+    }
+
+    /// Fetches all foregrounds.
+    /// Corresponds to GET /v2/emblem/foregrounds?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<EmblemLayer>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/emblem/foregrounds"))
+            .await
+    }
+
+    /// Fetches a single emblem foreground.
+    /// Corresponds to GET /v2/emblem/foregrounds/{id}
+    pub async fn get_foreground(client: &Client, id: u32) -> Result<EmblemLayer, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/emblem/foregrounds/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple foregrounds.
+    /// Corresponds to GET /v2/emblem/foregrounds?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_foregrounds(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<EmblemLayer>, GetManyError> {
+        get_many(client, "/v2/emblem/foregrounds", ids).await
+    }
+}
+
+/// Definitions for the /v2/emblem/backgrounds endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/emblem
+pub mod backgrounds {
+    use super::EmblemLayer;
+    use crate::api::{client, get_many, Client, GetManyError};
+
+    /// Fetches all emblem background ids.
+    /// Corresponds to GET /v2/emblem/backgrounds
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/emblem/backgrounds")).await
+    }
+
+    /// Fetches all backgrounds.
+    /// Corresponds to GET /v2/emblem/backgrounds?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<EmblemLayer>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/emblem/backgrounds"))
+            .await
+    }
+
+    /// Fetches a single emblem background.
+    /// Corresponds to GET /v2/emblem/backgrounds/{id}
+    pub async fn get_background(client: &Client, id: u32) -> Result<EmblemLayer, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/emblem/backgrounds/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple backgrounds.
+    /// Corresponds to GET /v2/emblem/backgrounds?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_backgrounds(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<EmblemLayer>, GetManyError> {
+        get_many(client, "/v2/emblem/backgrounds", ids).await
+    }
+}
@@ -0,0 +1,45 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Emote {
+    /// The emote id (e.g. "rockout").
+    pub id: String,
+    /// The chat commands that trigger the emote (e.g. "/rockout").
+    pub commands: Vec<String>,
+    /// The items that unlock the emote. Empty for emotes unlocked by default.
+    #[serde(default)]
+    pub unlock_items: Vec<ItemId>,
+}
+
+impl Identified for Emote {
+    type Id = String;
+
+    fn id(&self) -> &String {
+        &self.id
+    }
+}
+
+/// Fetches all emote ids.
+/// Corresponds to GET /v2/emotes
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/emotes")).await
+}
+
+/// Fetches all emotes.
+/// Corresponds to GET /v2/emotes?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Emote>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/emotes")).await
+}
+
+/// Fetches a single emote.
+/// Corresponds to GET /v2/emotes/{id}
+pub async fn get_emote(client: &Client, id: &str) -> Result<Emote, client::GetError> {
+    client.get(&client.url(&format!("/v2/emotes/{}", id))).await
+}
+
+/// Fetches multiple emotes.
+/// Corresponds to GET /v2/emotes?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_emotes(client: &Client, ids: &[String]) -> Result<Vec<Emote>, GetManyError> {
+    get_many(client, "/v2/emotes", ids).await
+}
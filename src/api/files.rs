@@ -0,0 +1,42 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct File {
+    /// The file id (e.g. "map_complete").
+    pub id: String,
+    /// The url of the asset.
+    pub icon: String,
+}
+
+impl Identified for File {
+    type Id = String;
+
+    fn id(&self) -> &String {
+        &self.id
+    }
+}
+
+/// Fetches all file ids.
+/// Corresponds to GET /v2/files
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/files")).await
+}
+
+/// Fetches all files.
+/// Corresponds to GET /v2/files?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<File>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/files")).await
+}
+
+/// Fetches a single file.
+/// Corresponds to GET /v2/files/{id}
+pub async fn get_file(client: &Client, id: &str) -> Result<File, client::GetError> {
+    client.get(&client.url(&format!("/v2/files/{}", id))).await
+}
+
+/// Fetches multiple files.
+/// Corresponds to GET /v2/files?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_files(client: &Client, ids: &[String]) -> Result<Vec<File>, GetManyError> {
+    get_many(client, "/v2/files", ids).await
+}
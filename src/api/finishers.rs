@@ -0,0 +1,58 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Finisher {
+    /// The finisher id.
+    pub id: u32,
+    /// The localized name of the finisher.
+    pub name: String,
+    /// The localized description of how to unlock the finisher (HTML formatted).
+    pub unlock_details: String,
+    /// The items that unlock the finisher.
+    #[serde(default)]
+    pub unlock_items: Vec<ItemId>,
+    /// The sort order of the finisher in the wardrobe.
+    pub order: u32,
+    /// The url of the finisher's icon.
+    pub icon: String,
+}
+
+impl Identified for Finisher {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all finisher ids.
+/// Corresponds to GET /v2/finishers
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/finishers")).await
+}
+
+/// Fetches all finishers.
+/// Corresponds to GET /v2/finishers?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Finisher>, client::GetError> {
+    client
+        .get_all_by_ids_all(&client.url("/v2/finishers"))
+        .await
+}
+
+/// Fetches a single finisher.
+/// Corresponds to GET /v2/finishers/{id}
+pub async fn get_finisher(client: &Client, id: u32) -> Result<Finisher, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/finishers/{}", id)))
+        .await
+}
+
+/// Fetches multiple finishers.
+/// Corresponds to GET /v2/finishers?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_finishers(
+    client: &Client,
+    ids: &[u32],
+) -> Result<Vec<Finisher>, GetManyError> {
+    get_many(client, "/v2/finishers", ids).await
+}
@@ -0,0 +1,56 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Glider {
+    /// The glider id.
+    pub id: u32,
+    /// The localized name of the glider.
+    pub name: String,
+    /// The localized description of the glider.
+    pub description: String,
+    /// The url of the glider's icon.
+    pub icon: String,
+    /// The sort order of the glider in the wardrobe.
+    pub order: u32,
+    /// The default dye color ids of the glider, see `colors`.
+    #[serde(default)]
+    pub default_dyes: Vec<u32>,
+    /// The items that unlock the glider.
+    #[serde(default)]
+    pub unlock_items: Vec<ItemId>,
+}
+
+impl Identified for Glider {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all glider ids.
+/// Corresponds to GET /v2/gliders
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/gliders")).await
+}
+
+/// Fetches all gliders.
+/// Corresponds to GET /v2/gliders?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Glider>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/gliders")).await
+}
+
+/// Fetches a single glider.
+/// Corresponds to GET /v2/gliders/{id}
+pub async fn get_glider(client: &Client, id: u32) -> Result<Glider, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/gliders/{}", id)))
+        .await
+}
+
+/// Fetches multiple gliders.
+/// Corresponds to GET /v2/gliders?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_gliders(client: &Client, ids: &[u32]) -> Result<Vec<Glider>, GetManyError> {
+    get_many(client, "/v2/gliders", ids).await
+}
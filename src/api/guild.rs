@@ -0,0 +1,238 @@
+use super::{client, Client};
+
+/// Represents a Guild Wars 2 Guild ID (a UUID string).
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq, Hash)]
+pub struct GuildId(pub String);
+
+impl std::fmt::Display for GuildId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct StorageItem {
+    /// The guild upgrade id of the stored consumable.
+    pub id: u32,
+    /// The amount of the consumable currently in storage.
+    pub count: u32,
+}
+
+/// Fetches the consumables in a guild's storage.
+/// Corresponds to GET /v2/guild/{id}/storage
+/// Requires authentication: 'account', 'guilds' scopes, and the key owner must be the guild leader.
+pub async fn get_storage(
+    client: &Client,
+    guild_id: &GuildId,
+) -> Result<Vec<StorageItem>, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/guild/{}/storage", guild_id)))
+        .await
+}
+
+/// Fetches the ids of all upgrades a guild has completed.
+/// Corresponds to GET /v2/guild/{id}/upgrades
+/// Requires authentication: 'account', 'guilds' scopes, and the key owner must be the guild leader.
+pub async fn get_upgrades(
+    client: &Client,
+    guild_id: &GuildId,
+) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/guild/{}/upgrades", guild_id)))
+        .await
+}
+
+/// Searches for guilds by their exact name, returning the matching guild ids.
+/// Corresponds to GET /v2/guild/search?name=...
+pub async fn search_by_name(client: &Client, name: &str) -> Result<Vec<GuildId>, client::GetError> {
+    let url = reqwest::Url::parse_with_params(&client.url("/v2/guild/search"), &[("name", name)])
+        .expect("guild search url should be valid");
+
+    client.get(url.as_str()).await
+}
+
+/// Definitions for the /v2/guild/permissions endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/guild/permissions
+pub mod permissions {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Permission {
+        /// The permission id (e.g. "StartingRole").
+        pub id: String,
+        /// The localized name of the permission.
+        pub name: String,
+        /// The localized description of the permission.
+        pub description: String,
+    }
+
+    impl Identified for Permission {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches all guild permission ids.
+    /// Corresponds to GET /v2/guild/permissions
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/guild/permissions")).await
+    }
+
+    /// Fetches a single guild permission.
+    /// Corresponds to GET /v2/guild/permissions/{id}
+    pub async fn get_permission(client: &Client, id: &str) -> Result<Permission, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/guild/permissions/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple guild permissions.
+    /// Corresponds to GET /v2/guild/permissions?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_permissions(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Permission>, GetManyError> {
+        get_many(client, "/v2/guild/permissions", ids).await
+    }
+}
+
+/// Definitions for the /v2/guild/upgrades endpoint (static upgrade definitions).
+/// See: https://wiki.guildwars2.com/wiki/API:2/guild/upgrades
+pub mod upgrade_defs {
+    use crate::api::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+    #[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum UpgradeKind {
+        AccumulatingCurrency,
+        BankBag,
+        Boost,
+        Claimable,
+        Consumable,
+        Decoration,
+        GuildHall,
+        GuildHallExpedition,
+        Hub,
+        Queue,
+        Unlock,
+        /// A kind added to the API after this crate was written.
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum CostKind {
+        Item,
+        Collectible,
+        Currency,
+        Coins,
+        /// A kind added to the API after this crate was written.
+        #[serde(other)]
+        Unknown,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct UpgradeCost {
+        /// What kind of resource is consumed.
+        #[serde(rename = "type")]
+        pub kind: CostKind,
+        /// The name of the consumed resource. Missing for coin costs.
+        pub name: Option<String>,
+        /// The amount of the resource required (copper for coin costs).
+        pub count: u32,
+        /// The item consumed, present for item and most collectible costs.
+        pub item_id: Option<ItemId>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct UpgradeDef {
+        /// The upgrade id.
+        pub id: u32,
+        /// The localized name of the upgrade.
+        pub name: String,
+        /// The localized description of the upgrade.
+        pub description: String,
+        /// The kind of upgrade.
+        #[serde(rename = "type")]
+        pub kind: UpgradeKind,
+        /// The url of the upgrade's icon.
+        pub icon: String,
+        /// The time it takes to build the upgrade, in minutes.
+        pub build_time: u32,
+        /// The guild level required before the upgrade can be built.
+        pub required_level: u32,
+        /// The guild experience granted by completing the upgrade.
+        pub experience: u32,
+        /// The upgrade ids that must be completed first.
+        pub prerequisites: Vec<u32>,
+        /// The resources consumed to build the upgrade.
+        pub costs: Vec<UpgradeCost>,
+        /// Maximum number of items a bank bag upgrade can hold.
+        pub bag_max_items: Option<u32>,
+        /// Maximum number of coins a bank bag upgrade can hold.
+        pub bag_max_coins: Option<u32>,
+    }
+
+    impl Identified for UpgradeDef {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all guild upgrade ids.
+    /// Corresponds to GET /v2/guild/upgrades
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/guild/upgrades")).await
+    }
+
+    /// Fetches a single guild upgrade definition.
+    /// Corresponds to GET /v2/guild/upgrades/{id}
+    pub async fn get_upgrade_def(client: &Client, id: u32) -> Result<UpgradeDef, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/guild/upgrades/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple guild upgrade definitions.
+    /// Corresponds to GET /v2/guild/upgrades?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_upgrade_defs(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<UpgradeDef>, GetManyError> {
+        get_many(client, "/v2/guild/upgrades", ids).await
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn unknown_kinds_deserialize() {
+            let def: UpgradeDef = serde_json::from_value(serde_json::json!({
+                "id": 38,
+                "name": "Guild Hall Banner",
+                "description": "",
+                "type": "SomethingNew",
+                "icon": "https://render.guildwars2.com/file/icon.png",
+                "build_time": 0,
+                "required_level": 1,
+                "experience": 0,
+                "prerequisites": [],
+                "costs": [
+                    { "type": "Coins", "count": 500 },
+                    { "type": "Favor", "name": "Favor", "count": 10 },
+                ],
+            }))
+            .unwrap();
+
+            assert_eq!(def.kind, UpgradeKind::Unknown);
+            assert_eq!(def.costs[0].kind, CostKind::Coins);
+            assert_eq!(def.costs[1].kind, CostKind::Unknown);
+        }
+    }
+}
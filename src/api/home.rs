@@ -0,0 +1,62 @@
+/// Definitions for the /v2/home/cats endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/home/cats
+pub mod cats {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Cat {
+        /// The cat id.
+        pub id: u32,
+        /// A short hint on how the cat is obtained (e.g. "fancy").
+        pub hint: String,
+    }
+
+    impl Identified for Cat {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all home instance cat ids.
+    /// Corresponds to GET /v2/home/cats
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/home/cats")).await
+    }
+
+    /// Fetches all cats.
+    /// Corresponds to GET /v2/home/cats?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Cat>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/home/cats"))
+            .await
+    }
+
+    /// Fetches a single home instance cat.
+    /// Corresponds to GET /v2/home/cats/{id}
+    pub async fn get_cat(client: &Client, id: u32) -> Result<Cat, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/home/cats/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple cats.
+    /// Corresponds to GET /v2/home/cats?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_cats(client: &Client, ids: &[u32]) -> Result<Vec<Cat>, GetManyError> {
+        get_many(client, "/v2/home/cats", ids).await
+    }
+}
+
+/// Definitions for the /v2/home/nodes endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/home/nodes
+pub mod nodes {
+    use crate::api::{client, Client};
+
+    /// Fetches the ids of all home instance gathering nodes (e.g. "quartz_node").
+    /// Corresponds to GET /v2/home/nodes
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/home/nodes")).await
+    }
+}
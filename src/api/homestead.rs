@@ -0,0 +1,184 @@
+/// Definitions for the /v2/homestead/decorations endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/homestead/decorations
+pub mod decorations {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Decoration {
+        /// The decoration id.
+        pub id: u32,
+        /// The localized name of the decoration.
+        pub name: String,
+        /// The localized description of the decoration.
+        pub description: String,
+        /// The maximum number of this decoration that can be stored.
+        pub max_count: u32,
+        /// The url of the decoration's icon.
+        pub icon: String,
+        /// The categories of the decoration, see `homestead::decoration_categories`.
+        pub categories: Vec<u32>,
+    }
+
+    impl Identified for Decoration {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all homestead decoration ids.
+    /// Corresponds to GET /v2/homestead/decorations
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/homestead/decorations")).await
+    }
+
+    /// Fetches all decorations.
+    /// Corresponds to GET /v2/homestead/decorations?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Decoration>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/homestead/decorations"))
+            .await
+    }
+
+    /// Fetches a single homestead decoration.
+    /// Corresponds to GET /v2/homestead/decorations/{id}
+    pub async fn get_decoration(client: &Client, id: u32) -> Result<Decoration, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/homestead/decorations/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple decorations.
+    /// Corresponds to GET /v2/homestead/decorations?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_decorations(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<Decoration>, GetManyError> {
+        get_many(client, "/v2/homestead/decorations", ids).await
+    }
+}
+
+/// Definitions for the /v2/homestead/decorations/categories endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/homestead/decorations/categories
+pub mod decoration_categories {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct DecorationCategory {
+        /// The decoration category id.
+        pub id: u32,
+        /// The localized name of the category.
+        pub name: String,
+    }
+
+    impl Identified for DecorationCategory {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all decoration category ids.
+    /// Corresponds to GET /v2/homestead/decorations/categories
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client
+            .get(&client.url("/v2/homestead/decorations/categories"))
+            .await
+    }
+
+    /// Fetches all categories.
+    /// Corresponds to GET /v2/homestead/decorations/categories?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<DecorationCategory>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/homestead/decorations/categories"))
+            .await
+    }
+
+    /// Fetches a single decoration category.
+    /// Corresponds to GET /v2/homestead/decorations/categories/{id}
+    pub async fn get_category(
+        client: &Client,
+        id: u32,
+    ) -> Result<DecorationCategory, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/homestead/decorations/categories/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple categories.
+    /// Corresponds to GET /v2/homestead/decorations/categories?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_categories(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<DecorationCategory>, GetManyError> {
+        get_many(client, "/v2/homestead/decorations/categories", ids).await
+    }
+}
+
+/// Definitions for the /v2/homestead/glyphs endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/homestead/glyphs
+pub mod glyphs {
+    use crate::api::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+    #[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+    #[serde(rename_all = "lowercase")]
+    pub enum GlyphSlot {
+        Harvesting,
+        Logging,
+        Mining,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Glyph {
+        /// The glyph id (e.g. "leatherworker_harvesting").
+        pub id: String,
+        /// The gathering tool slot the glyph applies to.
+        pub slot: GlyphSlot,
+        /// The item that unlocks the glyph.
+        pub item_id: ItemId,
+    }
+
+    impl Identified for Glyph {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches all homestead glyph ids.
+    /// Corresponds to GET /v2/homestead/glyphs
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/homestead/glyphs")).await
+    }
+
+    /// Fetches all glyphs.
+    /// Corresponds to GET /v2/homestead/glyphs?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Glyph>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/homestead/glyphs"))
+            .await
+    }
+
+    /// Fetches a single homestead glyph.
+    /// Corresponds to GET /v2/homestead/glyphs/{id}
+    pub async fn get_glyph(client: &Client, id: &str) -> Result<Glyph, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/homestead/glyphs/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple glyphs.
+    /// Corresponds to GET /v2/homestead/glyphs?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_glyphs(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Glyph>, GetManyError> {
+        get_many(client, "/v2/homestead/glyphs", ids).await
+    }
+}
@@ -0,0 +1,71 @@
+use super::{client, get_static_chunked, BulkResult, Client, Identified, ItemId};
+use crate::coin::Coin;
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Item {
+    /// The item id.
+    pub id: ItemId,
+    /// The localized name of the item.
+    pub name: String,
+    /// A localized description of the item, if any.
+    pub description: Option<String>,
+    /// The item type, e.g. `Weapon` or `CraftingMaterial`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The item rarity, e.g. `Exotic`.
+    pub rarity: String,
+    /// The level required to use the item.
+    pub level: u32,
+    /// What a merchant pays for the item.
+    pub vendor_value: Coin,
+    /// The skin the item has by default, if any.
+    pub default_skin: Option<u32>,
+    /// Flags like `AccountBound` or `NoSell`.
+    pub flags: Vec<String>,
+    /// The game modes the item can be used in.
+    pub game_types: Vec<String>,
+    /// The races or professions the item is restricted to, if any.
+    pub restrictions: Vec<String>,
+    /// The url of the item's icon.
+    pub icon: Option<String>,
+    /// The chat link of the item.
+    pub chat_link: String,
+}
+
+impl Item {
+    /// What a merchant pays for the item, unless merchants don't buy it.
+    pub fn vendor_price(&self) -> Option<Coin> {
+        let sellable = !self.flags.iter().any(|flag| flag == "NoSell");
+        (sellable && self.vendor_value > Coin::ZERO).then_some(self.vendor_value)
+    }
+}
+
+impl Identified for Item {
+    type Id = ItemId;
+
+    fn id(&self) -> &ItemId {
+        &self.id
+    }
+}
+
+/// Fetches all item ids.
+/// Corresponds to GET /v2/items
+pub async fn get_all_ids(client: &Client) -> Result<Vec<ItemId>, client::GetError> {
+    client.get_cached(&client.url("/v2/items")).await
+}
+
+/// Fetches a single item.
+/// Corresponds to GET /v2/items/{id}
+pub async fn get_item(client: &Client, id: ItemId) -> Result<Item, client::GetError> {
+    client.get(&client.url(&format!("/v2/items/{}", id))).await
+}
+
+/// Fetches any number of items, split into requests of at most 200 ids. Ids without an
+/// item are reported as missing. Served from the client's disk cache, if any.
+/// Corresponds to GET /v2/items?ids=...
+pub async fn get_items_chunked(
+    client: &Client,
+    ids: &[ItemId],
+) -> Result<BulkResult<ItemId, Item>, client::GetError> {
+    get_static_chunked(client, "/v2/items", ids).await
+}
@@ -0,0 +1,48 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct JadeBot {
+    /// The jade bot skin id.
+    pub id: u32,
+    /// The localized name of the skin.
+    pub name: String,
+    /// The localized description of the skin.
+    pub description: String,
+    /// The item that unlocks the skin.
+    pub unlock_item: ItemId,
+}
+
+impl Identified for JadeBot {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all jade bot skin ids.
+/// Corresponds to GET /v2/jadebots
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/jadebots")).await
+}
+
+/// Fetches all jadebots.
+/// Corresponds to GET /v2/jadebots?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<JadeBot>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/jadebots")).await
+}
+
+/// Fetches a single jade bot skin.
+/// Corresponds to GET /v2/jadebots/{id}
+pub async fn get_jadebot(client: &Client, id: u32) -> Result<JadeBot, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/jadebots/{}", id)))
+        .await
+}
+
+/// Fetches multiple jadebots.
+/// Corresponds to GET /v2/jadebots?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_jadebots(client: &Client, ids: &[u32]) -> Result<Vec<JadeBot>, GetManyError> {
+    get_many(client, "/v2/jadebots", ids).await
+}
@@ -0,0 +1,55 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Legend {
+    /// The legend id (e.g. "Legend1").
+    pub id: String,
+    /// The legend's build template code.
+    pub code: Option<u32>,
+    /// The skill id of the legend swap skill.
+    pub swap: u32,
+    /// The skill id of the legend's heal skill.
+    pub heal: u32,
+    /// The skill id of the legend's elite skill.
+    pub elite: u32,
+    /// The skill ids of the legend's utility skills.
+    pub utilities: Vec<u32>,
+}
+
+impl Identified for Legend {
+    type Id = String;
+
+    fn id(&self) -> &String {
+        &self.id
+    }
+}
+
+/// Fetches all legend ids.
+/// Corresponds to GET /v2/legends
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/legends")).await
+}
+
+/// Fetches all legends.
+/// Corresponds to GET /v2/legends?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Legend>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/legends")).await
+}
+
+/// Fetches a single legend.
+/// Corresponds to GET /v2/legends/{id}
+pub async fn get_legend(client: &Client, id: &str) -> Result<Legend, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/legends/{}", id)))
+        .await
+}
+
+/// Fetches multiple legends.
+/// Corresponds to GET /v2/legends?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_legends(
+    client: &Client,
+    ids: &[String],
+) -> Result<Vec<Legend>, GetManyError> {
+    get_many(client, "/v2/legends", ids).await
+}
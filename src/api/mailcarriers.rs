@@ -0,0 +1,59 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MailCarrier {
+    /// The mail carrier id.
+    pub id: u32,
+    /// The localized name of the mail carrier.
+    pub name: String,
+    /// The url of the mail carrier's icon.
+    pub icon: String,
+    /// The sort order of the mail carrier in the wardrobe.
+    pub order: u32,
+    /// Additional flags (e.g. "Default").
+    #[serde(default)]
+    pub flags: Vec<String>,
+    /// The items that unlock the mail carrier.
+    #[serde(default)]
+    pub unlock_items: Vec<ItemId>,
+}
+
+impl Identified for MailCarrier {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all mail carrier ids.
+/// Corresponds to GET /v2/mailcarriers
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/mailcarriers")).await
+}
+
+/// Fetches all mailcarriers.
+/// Corresponds to GET /v2/mailcarriers?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<MailCarrier>, client::GetError> {
+    client
+        .get_all_by_ids_all(&client.url("/v2/mailcarriers"))
+        .await
+}
+
+/// Fetches a single mail carrier.
+/// Corresponds to GET /v2/mailcarriers/{id}
+pub async fn get_mailcarrier(client: &Client, id: u32) -> Result<MailCarrier, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/mailcarriers/{}", id)))
+        .await
+}
+
+/// Fetches multiple mailcarriers.
+/// Corresponds to GET /v2/mailcarriers?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_mailcarriers(
+    client: &Client,
+    ids: &[u32],
+) -> Result<Vec<MailCarrier>, GetManyError> {
+    get_many(client, "/v2/mailcarriers", ids).await
+}
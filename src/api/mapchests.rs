@@ -0,0 +1,8 @@
+use super::{client, Client};
+
+/// Fetches the ids of all daily hero's choice map chests, as reported by the account map
+/// chests endpoint.
+/// Corresponds to GET /v2/mapchests
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/mapchests")).await
+}
@@ -0,0 +1,66 @@
+use super::continents::Rect;
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Map {
+    /// The map id.
+    pub id: u32,
+    /// The localized name of the map.
+    pub name: String,
+    /// The minimal level of the map.
+    pub min_level: u32,
+    /// The maximal level of the map.
+    pub max_level: u32,
+    /// The default floor of the map.
+    pub default_floor: i32,
+    /// The kind of map (e.g. "Public", "Instance", "Center").
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The floors the map spans.
+    pub floors: Vec<i32>,
+    /// The region the map belongs to, see `continents`.
+    pub region_id: Option<u32>,
+    /// The localized name of the region.
+    pub region_name: Option<String>,
+    /// The continent the map belongs to, see `continents`.
+    pub continent_id: Option<u32>,
+    /// The localized name of the continent.
+    pub continent_name: Option<String>,
+    /// The rectangle the map covers in its own coordinate system.
+    pub map_rect: Rect,
+    /// The rectangle the map covers on the continent.
+    pub continent_rect: Rect,
+}
+
+impl Identified for Map {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all map ids.
+/// Corresponds to GET /v2/maps
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/maps")).await
+}
+
+/// Fetches all maps.
+/// Corresponds to GET /v2/maps?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Map>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/maps")).await
+}
+
+/// Fetches a single map.
+/// Corresponds to GET /v2/maps/{id}
+pub async fn get_map(client: &Client, id: u32) -> Result<Map, client::GetError> {
+    client.get(&client.url(&format!("/v2/maps/{}", id))).await
+}
+
+/// Fetches multiple maps.
+/// Corresponds to GET /v2/maps?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_maps(client: &Client, ids: &[u32]) -> Result<Vec<Map>, GetManyError> {
+    get_many(client, "/v2/maps", ids).await
+}
@@ -0,0 +1,82 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MasteryLevel {
+    /// The localized name of the level.
+    pub name: String,
+    /// The localized description of the level.
+    pub description: String,
+    /// The localized in-game instructions for the level.
+    pub instruction: String,
+    /// The url of the level's icon.
+    pub icon: String,
+    /// The mastery points required to train the level.
+    pub point_cost: u32,
+    /// The experience required to train the level.
+    pub exp_cost: u32,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Mastery {
+    /// The mastery track id.
+    pub id: u32,
+    /// The localized name of the mastery track.
+    pub name: String,
+    /// The localized unlock requirement of the track.
+    pub requirement: String,
+    /// The sort order of the track within its region.
+    pub order: u32,
+    /// The url of the track's background art.
+    pub background: String,
+    /// The mastery region the track belongs to (e.g. "Tyria", "Maguuma").
+    pub region: String,
+    /// The levels of the track, in training order.
+    pub levels: Vec<MasteryLevel>,
+}
+
+impl Identified for Mastery {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+impl Mastery {
+    /// The total mastery points required to train every level of the track.
+    pub fn total_point_cost(&self) -> u32 {
+        self.levels.iter().map(|level| level.point_cost).sum()
+    }
+}
+
+/// Fetches all mastery track ids.
+/// Corresponds to GET /v2/masteries
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/masteries")).await
+}
+
+/// Fetches all masteries.
+/// Corresponds to GET /v2/masteries?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Mastery>, client::GetError> {
+    client
+        .get_all_by_ids_all(&client.url("/v2/masteries"))
+        .await
+}
+
+/// Fetches a single mastery track.
+/// Corresponds to GET /v2/masteries/{id}
+pub async fn get_mastery(client: &Client, id: u32) -> Result<Mastery, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/masteries/{}", id)))
+        .await
+}
+
+/// Fetches multiple masteries.
+/// Corresponds to GET /v2/masteries?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_masteries(
+    client: &Client,
+    ids: &[u32],
+) -> Result<Vec<Mastery>, GetManyError> {
+    get_many(client, "/v2/masteries", ids).await
+}
@@ -0,0 +1,56 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct MaterialCategory {
+    /// The material category id.
+    pub id: u32,
+    /// The localized name of the category.
+    pub name: String,
+    /// The items stored in the category.
+    pub items: Vec<ItemId>,
+    /// The sort order of the category in material storage.
+    pub order: u32,
+}
+
+impl Identified for MaterialCategory {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all material category ids.
+/// Corresponds to GET /v2/materials
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/materials")).await
+}
+
+/// Fetches all material categories.
+/// Corresponds to GET /v2/materials?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<MaterialCategory>, client::GetError> {
+    client
+        .get_all_by_ids_all(&client.url("/v2/materials"))
+        .await
+}
+
+/// Fetches a single material category.
+/// Corresponds to GET /v2/materials/{id}
+pub async fn get_material_category(
+    client: &Client,
+    id: u32,
+) -> Result<MaterialCategory, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/materials/{}", id)))
+        .await
+}
+
+/// Fetches multiple material categories.
+/// Corresponds to GET /v2/materials?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_material_categories(
+    client: &Client,
+    ids: &[u32],
+) -> Result<Vec<MaterialCategory>, GetManyError> {
+    get_many(client, "/v2/materials", ids).await
+}
@@ -0,0 +1,50 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Mini {
+    /// The miniature id.
+    pub id: u32,
+    /// The localized name of the miniature.
+    pub name: String,
+    /// A localized description of how to unlock the miniature, if any.
+    pub unlock: Option<String>,
+    /// The url of the miniature's icon.
+    pub icon: String,
+    /// The sort order of the miniature in the wardrobe.
+    pub order: u32,
+    /// The item that unlocks the miniature.
+    pub item_id: ItemId,
+}
+
+impl Identified for Mini {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all miniature ids.
+/// Corresponds to GET /v2/minis
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/minis")).await
+}
+
+/// Fetches all minis.
+/// Corresponds to GET /v2/minis?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Mini>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/minis")).await
+}
+
+/// Fetches a single miniature.
+/// Corresponds to GET /v2/minis/{id}
+pub async fn get_mini(client: &Client, id: u32) -> Result<Mini, client::GetError> {
+    client.get(&client.url(&format!("/v2/minis/{}", id))).await
+}
+
+/// Fetches multiple minis.
+/// Corresponds to GET /v2/minis?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_minis(client: &Client, ids: &[u32]) -> Result<Vec<Mini>, GetManyError> {
+    get_many(client, "/v2/minis", ids).await
+}
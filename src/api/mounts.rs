@@ -0,0 +1,135 @@
+/// Definitions for the /v2/mounts/types endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/mounts/types
+pub mod types {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MountSkill {
+        /// The skill id.
+        pub id: u32,
+        /// The slot the skill is bound to (e.g. "Weapon_1").
+        pub slot: String,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MountType {
+        /// The mount type id (e.g. "raptor").
+        pub id: String,
+        /// The localized name of the mount.
+        pub name: String,
+        /// The skin the mount uses by default, see `mounts::skins`.
+        pub default_skin: u32,
+        /// All skins available for the mount, see `mounts::skins`.
+        pub skins: Vec<u32>,
+        /// The mount's skills.
+        pub skills: Vec<MountSkill>,
+    }
+
+    impl Identified for MountType {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches all mount type ids.
+    /// Corresponds to GET /v2/mounts/types
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/mounts/types")).await
+    }
+
+    /// Fetches all types.
+    /// Corresponds to GET /v2/mounts/types?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<MountType>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/mounts/types"))
+            .await
+    }
+
+    /// Fetches a single mount type.
+    /// Corresponds to GET /v2/mounts/types/{id}
+    pub async fn get_type(client: &Client, id: &str) -> Result<MountType, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/mounts/types/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple types.
+    /// Corresponds to GET /v2/mounts/types?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_types(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<MountType>, GetManyError> {
+        get_many(client, "/v2/mounts/types", ids).await
+    }
+}
+
+/// Definitions for the /v2/mounts/skins endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/mounts/skins
+pub mod skins {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct DyeSlot {
+        /// The default color of the slot, see `colors`.
+        pub color_id: u32,
+        /// The material the slot is dyed as (e.g. "metal", "leather").
+        pub material: String,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MountSkin {
+        /// The mount skin id.
+        pub id: u32,
+        /// The localized name of the skin.
+        pub name: String,
+        /// The url of the skin's icon.
+        pub icon: String,
+        /// The mount type the skin applies to, see `mounts::types`.
+        pub mount: String,
+        /// The dye slots of the skin and their default colors.
+        pub dye_slots: Vec<DyeSlot>,
+    }
+
+    impl Identified for MountSkin {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all mount skin ids.
+    /// Corresponds to GET /v2/mounts/skins
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/mounts/skins")).await
+    }
+
+    /// Fetches all skins.
+    /// Corresponds to GET /v2/mounts/skins?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<MountSkin>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/mounts/skins"))
+            .await
+    }
+
+    /// Fetches a single mount skin.
+    /// Corresponds to GET /v2/mounts/skins/{id}
+    pub async fn get_skin(client: &Client, id: u32) -> Result<MountSkin, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/mounts/skins/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple skins.
+    /// Corresponds to GET /v2/mounts/skins?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_skins(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<MountSkin>, GetManyError> {
+        get_many(client, "/v2/mounts/skins", ids).await
+    }
+}
@@ -0,0 +1,67 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoveltySlot {
+    Chair,
+    Music,
+    HeldItem,
+    Miscellaneous,
+    Tonic,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Novelty {
+    /// The novelty id.
+    pub id: u32,
+    /// The localized name of the novelty.
+    pub name: String,
+    /// The localized description of the novelty.
+    pub description: String,
+    /// The url of the novelty's icon.
+    pub icon: String,
+    /// The novelty slot the novelty is equipped in.
+    pub slot: NoveltySlot,
+    /// The items that unlock the novelty.
+    #[serde(default)]
+    pub unlock_item: Vec<ItemId>,
+}
+
+impl Identified for Novelty {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all novelty ids.
+/// Corresponds to GET /v2/novelties
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/novelties")).await
+}
+
+/// Fetches all novelties.
+/// Corresponds to GET /v2/novelties?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Novelty>, client::GetError> {
+    client
+        .get_all_by_ids_all(&client.url("/v2/novelties"))
+        .await
+}
+
+/// Fetches a single novelty.
+/// Corresponds to GET /v2/novelties/{id}
+pub async fn get_novelty(client: &Client, id: u32) -> Result<Novelty, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/novelties/{}", id)))
+        .await
+}
+
+/// Fetches multiple novelties.
+/// Corresponds to GET /v2/novelties?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_novelties(
+    client: &Client,
+    ids: &[u32],
+) -> Result<Vec<Novelty>, GetManyError> {
+    get_many(client, "/v2/novelties", ids).await
+}
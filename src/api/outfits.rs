@@ -0,0 +1,49 @@
+use super::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Outfit {
+    /// The outfit id.
+    pub id: u32,
+    /// The localized name of the outfit.
+    pub name: String,
+    /// The url of the outfit's icon.
+    pub icon: String,
+    /// The items that unlock the outfit.
+    #[serde(default)]
+    pub unlock_items: Vec<ItemId>,
+}
+
+impl Identified for Outfit {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all outfit ids.
+/// Corresponds to GET /v2/outfits
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/outfits")).await
+}
+
+/// Fetches all outfits.
+/// Corresponds to GET /v2/outfits?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Outfit>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/outfits")).await
+}
+
+/// Fetches a single outfit.
+/// Corresponds to GET /v2/outfits/{id}
+pub async fn get_outfit(client: &Client, id: u32) -> Result<Outfit, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/outfits/{}", id)))
+        .await
+}
+
+/// Fetches multiple outfits.
+/// Corresponds to GET /v2/outfits?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_outfits(client: &Client, ids: &[u32]) -> Result<Vec<Outfit>, GetManyError> {
+    get_many(client, "/v2/outfits", ids).await
+}
@@ -0,0 +1,676 @@
+use std::collections::HashMap;
+
+use super::{client, get_many, Client, GetManyError, Identified};
+
+/// A playable profession.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Profession {
+    #[serde(alias = "Elementalist")]
+    Elementalist,
+    #[serde(alias = "Engineer")]
+    Engineer,
+    #[serde(alias = "Guardian")]
+    Guardian,
+    #[serde(alias = "Mesmer")]
+    Mesmer,
+    #[serde(alias = "Necromancer")]
+    Necromancer,
+    #[serde(alias = "Ranger")]
+    Ranger,
+    #[serde(alias = "Revenant")]
+    Revenant,
+    #[serde(alias = "Thief")]
+    Thief,
+    #[serde(alias = "Warrior")]
+    Warrior,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct WinLoss {
+    pub wins: u32,
+    pub losses: u32,
+    pub desertions: u32,
+    pub byes: u32,
+    pub forfeits: u32,
+}
+
+impl WinLoss {
+    /// The number of games that counted towards the record.
+    pub fn total(&self) -> u32 {
+        self.wins + self.losses + self.desertions + self.byes + self.forfeits
+    }
+
+    /// The fraction of games won (byes count as wins, as they do in game).
+    /// Returns `None` if no games were played.
+    pub fn win_rate(&self) -> Option<f64> {
+        let total = self.total();
+        if total == 0 {
+            return None;
+        }
+
+        Some((self.wins + self.byes) as f64 / total as f64)
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Stats {
+    /// The account's PvP rank.
+    pub pvp_rank: u32,
+    /// The PvP experience points towards the next rank.
+    pub pvp_rank_points: u32,
+    /// The number of times the account has rolled over the maximum rank.
+    pub pvp_rank_rollovers: u32,
+    /// The win/loss record over all games.
+    pub aggregate: WinLoss,
+    /// The win/loss record per profession played.
+    pub professions: HashMap<Profession, WinLoss>,
+    /// The win/loss record per ladder (e.g. "ranked", "unranked").
+    pub ladders: HashMap<String, WinLoss>,
+}
+
+impl Stats {
+    /// The win rate of every profession the account has played at least one game with.
+    pub fn profession_win_rates(&self) -> HashMap<Profession, f64> {
+        self.professions
+            .iter()
+            .filter_map(|(profession, record)| Some((*profession, record.win_rate()?)))
+            .collect()
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameResult {
+    Victory,
+    Defeat,
+    Forfeit,
+    Bye,
+    Desertion,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GameTeam {
+    Red,
+    Blue,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GameScores {
+    pub red: u32,
+    pub blue: u32,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Game {
+    /// The game id (a UUID string).
+    pub id: String,
+    /// The map the game was played on.
+    pub map_id: u32,
+    /// When the game started (ISO-8601 format string).
+    pub started: String,
+    /// When the game ended (ISO-8601 format string).
+    pub ended: String,
+    /// The outcome of the game for the account.
+    pub result: GameResult,
+    /// The team the account played on.
+    pub team: GameTeam,
+    /// The profession the account played.
+    pub profession: Profession,
+    /// The final score of each team.
+    pub scores: GameScores,
+    /// The kind of rating the game was played for (e.g. "Ranked", "Unranked", "None").
+    pub rating_type: String,
+    /// The change in rating caused by the game, for ranked games.
+    pub rating_change: Option<i32>,
+    /// The PvP season id the game was played in, for ranked games.
+    pub season: Option<String>,
+}
+
+impl Identified for Game {
+    type Id = String;
+
+    fn id(&self) -> &String {
+        &self.id
+    }
+}
+
+/// Fetches the account's PvP stats.
+/// Corresponds to GET /v2/pvp/stats
+/// Requires authentication: 'account', 'pvp' scopes.
+pub async fn get_stats(client: &Client) -> Result<Stats, client::GetError> {
+    client.get(&client.url("/v2/pvp/stats")).await
+}
+
+/// Fetches the ids of the account's most recent PvP games.
+/// Corresponds to GET /v2/pvp/games
+/// Requires authentication: 'account', 'pvp' scopes.
+pub async fn get_game_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/pvp/games")).await
+}
+
+/// Fetches a single PvP game.
+/// Corresponds to GET /v2/pvp/games/{id}
+/// Requires authentication: 'account', 'pvp' scopes.
+pub async fn get_game(client: &Client, id: &str) -> Result<Game, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/pvp/games/{}", id)))
+        .await
+}
+
+/// Fetches multiple PvP games.
+/// Corresponds to GET /v2/pvp/games?ids=...
+/// Requires authentication: 'account', 'pvp' scopes.
+pub async fn get_games(client: &Client, ids: &[String]) -> Result<Vec<Game>, GetManyError> {
+    get_many(client, "/v2/pvp/games", ids).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn win_rate_counts_byes_as_wins() {
+        let record = WinLoss {
+            wins: 5,
+            losses: 3,
+            desertions: 0,
+            byes: 1,
+            forfeits: 1,
+        };
+
+        assert_eq!(record.total(), 10);
+        assert_eq!(record.win_rate(), Some(0.6));
+    }
+
+    #[test]
+    fn win_rate_without_games() {
+        assert_eq!(WinLoss::default().win_rate(), None);
+    }
+}
+
+/// Definitions for the /v2/pvp/seasons endpoints, including season leaderboards.
+/// See: https://wiki.guildwars2.com/wiki/API:2/pvp/seasons
+pub mod seasons {
+    use std::collections::HashMap;
+
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+    use crate::client::{Paginated, PaginationParams};
+
+    /// A leaderboard region.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum Region {
+        Na,
+        Eu,
+    }
+
+    impl std::fmt::Display for Region {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            match self {
+                Region::Na => write!(f, "na"),
+                Region::Eu => write!(f, "eu"),
+            }
+        }
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct DivisionTier {
+        /// The number of pips required to complete the tier.
+        pub points: u32,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Division {
+        /// The localized name of the division.
+        pub name: String,
+        /// Division flags (e.g. "CanLosePoints", "Repeatable").
+        pub flags: Vec<String>,
+        /// The url of the division's large icon.
+        pub large_icon: String,
+        /// The url of the division's small icon.
+        pub small_icon: String,
+        /// The url of the division's pip icon.
+        pub pip_icon: String,
+        /// The tiers of the division.
+        pub tiers: Vec<DivisionTier>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct LeaderboardTier {
+        /// The inclusive score range of the tier.
+        pub range: [f64; 2],
+        /// The display color of the tier.
+        pub color: Option<String>,
+        /// The kind of tier (e.g. "Rank").
+        #[serde(rename = "type")]
+        pub kind: Option<String>,
+        /// The localized name of the tier.
+        pub name: Option<String>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct LeaderboardSettings {
+        /// The localized name of the leaderboard.
+        pub name: String,
+        /// How long the leaderboard runs for.
+        pub duration: Option<u32>,
+        /// The scoring id used to rank entries.
+        pub scoring: String,
+        /// The tiers of the leaderboard.
+        pub tiers: Vec<LeaderboardTier>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Scoring {
+        /// The scoring id, referenced by leaderboard entries.
+        pub id: String,
+        /// The kind of value being scored (e.g. "Integer").
+        #[serde(rename = "type")]
+        pub kind: String,
+        /// The localized description of the scoring.
+        pub description: String,
+        /// The localized name of the scoring.
+        pub name: String,
+        /// Whether higher or lower values rank better ("MoreIsBetter" or "LessIsBetter").
+        pub ordering: String,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Leaderboard {
+        pub settings: LeaderboardSettings,
+        pub scorings: Vec<Scoring>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Season {
+        /// The season id (a UUID string).
+        pub id: String,
+        /// The localized name of the season.
+        pub name: String,
+        /// When the season starts (ISO-8601 format string).
+        pub start: String,
+        /// When the season ends (ISO-8601 format string).
+        pub end: String,
+        /// Whether the season is currently running.
+        pub active: bool,
+        /// The divisions of the season, from lowest to highest.
+        pub divisions: Vec<Division>,
+        /// The leaderboards of the season keyed by board id (e.g. "ladder", "legendary").
+        #[serde(default)]
+        pub leaderboards: HashMap<String, Leaderboard>,
+    }
+
+    impl Identified for Season {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct EntryScore {
+        /// The scoring id, see `Scoring::id`.
+        pub id: String,
+        /// The score value.
+        pub value: i64,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct LeaderboardEntry {
+        /// The account name, for account leaderboards.
+        pub name: Option<String>,
+        /// The position on the leaderboard.
+        pub rank: u32,
+        /// The guild id, for guild leaderboards.
+        pub id: Option<String>,
+        /// The team name, for team leaderboards.
+        pub team: Option<String>,
+        /// The team id, for team leaderboards.
+        pub team_id: Option<u32>,
+        /// When the entry was last updated (ISO-8601 format string).
+        pub date: String,
+        /// The scores of the entry.
+        pub scores: Vec<EntryScore>,
+    }
+
+    fn leaderboard_url(client: &Client, season_id: &str, board: &str, region: Region) -> String {
+        client.url(&format!(
+            "/v2/pvp/seasons/{}/leaderboards/{}/{}",
+            season_id, board, region
+        ))
+    }
+
+    /// Fetches all season ids.
+    /// Corresponds to GET /v2/pvp/seasons
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/pvp/seasons")).await
+    }
+
+    /// Fetches all seasons.
+    /// Corresponds to GET /v2/pvp/seasons?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Season>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/pvp/seasons"))
+            .await
+    }
+
+    /// Fetches a single season.
+    /// Corresponds to GET /v2/pvp/seasons/{id}
+    pub async fn get_season(client: &Client, id: &str) -> Result<Season, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/pvp/seasons/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple seasons.
+    /// Corresponds to GET /v2/pvp/seasons?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_seasons(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Season>, GetManyError> {
+        get_many(client, "/v2/pvp/seasons", ids).await
+    }
+
+    /// Fetches the ids of the leaderboards available for a season (e.g. "ladder").
+    /// Corresponds to GET /v2/pvp/seasons/{id}/leaderboards
+    pub async fn get_leaderboard_ids(
+        client: &Client,
+        season_id: &str,
+    ) -> Result<Vec<String>, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/pvp/seasons/{}/leaderboards", season_id)))
+            .await
+    }
+
+    /// Fetches a single page of a season leaderboard for a region.
+    /// Corresponds to paginated GET /v2/pvp/seasons/{id}/leaderboards/{board}/{region}
+    pub async fn get_leaderboard_page(
+        client: &Client,
+        season_id: &str,
+        board: &str,
+        region: Region,
+        params: PaginationParams,
+    ) -> Result<Paginated<Vec<LeaderboardEntry>>, client::PaginatedGetError> {
+        client
+            .get_paginated(&leaderboard_url(client, season_id, board, region), params)
+            .await
+    }
+
+    /// Fetches every entry of a season leaderboard for a region.
+    /// Corresponds to paginated GET /v2/pvp/seasons/{id}/leaderboards/{board}/{region}
+    pub async fn get_leaderboard(
+        client: &Client,
+        season_id: &str,
+        board: &str,
+        region: Region,
+    ) -> Result<Vec<LeaderboardEntry>, client::PaginatedGetError> {
+        client
+            .get_all_pages(
+                &leaderboard_url(client, season_id, board, region),
+                Default::default(),
+            )
+            .await
+    }
+}
+
+/// Definitions for the /v2/pvp/standings endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/pvp/standings
+pub mod standings {
+    use crate::api::{client, Client};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct SeasonProgress {
+        /// The total number of pips earned.
+        pub total_points: u32,
+        /// The index of the current division.
+        pub division: u32,
+        /// The index of the current tier within the division.
+        pub tier: u32,
+        /// The number of pips earned in the current tier.
+        pub points: u32,
+        /// The number of times the account has repeated the final division.
+        pub repeats: u32,
+        /// The account's skill rating, for seasons that track it.
+        pub rating: Option<u32>,
+        /// The account's decay, for seasons that track it.
+        pub decay: Option<u32>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Standing {
+        /// The season id, see `pvp::seasons`.
+        pub season_id: String,
+        /// The account's current progress in the season.
+        pub current: SeasonProgress,
+        /// The account's best progress in the season.
+        pub best: SeasonProgress,
+    }
+
+    /// Fetches the account's standings in every PvP season.
+    /// Corresponds to GET /v2/pvp/standings
+    /// Requires authentication: 'account', 'pvp' scopes.
+    pub async fn get_standings(client: &Client) -> Result<Vec<Standing>, client::GetError> {
+        client.get(&client.url("/v2/pvp/standings")).await
+    }
+}
+
+/// Definitions for the /v2/pvp/ranks endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/pvp/ranks
+pub mod ranks {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct RankLevel {
+        /// The minimum PvP rank of the level.
+        pub min_rank: u32,
+        /// The maximum PvP rank of the level.
+        pub max_rank: u32,
+        /// The experience points required to advance a rank.
+        pub points: u32,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Rank {
+        /// The rank id.
+        pub id: u32,
+        /// The finisher unlocked by the rank, see `finishers`.
+        pub finisher_id: u32,
+        /// The localized name of the rank.
+        pub name: String,
+        /// The url of the rank's icon.
+        pub icon: String,
+        /// The minimum PvP rank covered by this rank.
+        pub min_rank: u32,
+        /// The maximum PvP rank covered by this rank.
+        pub max_rank: u32,
+        /// The experience requirements of each level within the rank.
+        pub levels: Vec<RankLevel>,
+    }
+
+    impl Identified for Rank {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all rank ids.
+    /// Corresponds to GET /v2/pvp/ranks
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/pvp/ranks")).await
+    }
+
+    /// Fetches all ranks.
+    /// Corresponds to GET /v2/pvp/ranks?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Rank>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/pvp/ranks"))
+            .await
+    }
+
+    /// Fetches a single rank.
+    /// Corresponds to GET /v2/pvp/ranks/{id}
+    pub async fn get_rank(client: &Client, id: u32) -> Result<Rank, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/pvp/ranks/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple ranks.
+    /// Corresponds to GET /v2/pvp/ranks?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_ranks(client: &Client, ids: &[u32]) -> Result<Vec<Rank>, GetManyError> {
+        get_many(client, "/v2/pvp/ranks", ids).await
+    }
+}
+
+/// Definitions for the /v2/pvp/amulets endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/pvp/amulets
+pub mod amulets {
+    use std::collections::HashMap;
+
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Amulet {
+        /// The amulet id.
+        pub id: u32,
+        /// The localized name of the amulet.
+        pub name: String,
+        /// The url of the amulet's icon.
+        pub icon: String,
+        /// The attribute bonuses granted by the amulet (e.g. "Power" => 1200).
+        pub attributes: HashMap<String, u32>,
+    }
+
+    impl Identified for Amulet {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all amulet ids.
+    /// Corresponds to GET /v2/pvp/amulets
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/pvp/amulets")).await
+    }
+
+    /// Fetches all amulets.
+    /// Corresponds to GET /v2/pvp/amulets?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Amulet>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/pvp/amulets"))
+            .await
+    }
+
+    /// Fetches a single amulet.
+    /// Corresponds to GET /v2/pvp/amulets/{id}
+    pub async fn get_amulet(client: &Client, id: u32) -> Result<Amulet, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/pvp/amulets/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple amulets.
+    /// Corresponds to GET /v2/pvp/amulets?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_amulets(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<Amulet>, GetManyError> {
+        get_many(client, "/v2/pvp/amulets", ids).await
+    }
+}
+
+/// Definitions for the /v2/pvp/heroes endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/pvp/heroes
+pub mod heroes {
+    use crate::api::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct HeroStats {
+        pub offense: u32,
+        pub defense: u32,
+        pub speed: u32,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct HeroSkin {
+        /// The skin id.
+        pub id: u32,
+        /// The localized name of the skin.
+        pub name: String,
+        /// The url of the skin's icon.
+        pub icon: String,
+        /// Whether the skin is unlocked by default.
+        pub default: bool,
+        /// The items that unlock the skin.
+        #[serde(default)]
+        pub unlock_items: Vec<ItemId>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Hero {
+        /// The hero id (a UUID string).
+        pub id: String,
+        /// The localized name of the hero.
+        pub name: String,
+        /// The localized description of the hero.
+        pub description: String,
+        /// The hero's role (e.g. "Tank").
+        #[serde(rename = "type")]
+        pub kind: String,
+        /// The hero's base stats.
+        pub stats: HeroStats,
+        /// The url of the hero's overlay art.
+        pub overlay: String,
+        /// The url of the hero's underlay art.
+        pub underlay: String,
+        /// The skins available for the hero.
+        pub skins: Vec<HeroSkin>,
+    }
+
+    impl Identified for Hero {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches all hero ids.
+    /// Corresponds to GET /v2/pvp/heroes
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/pvp/heroes")).await
+    }
+
+    /// Fetches all heroes.
+    /// Corresponds to GET /v2/pvp/heroes?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Hero>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/pvp/heroes"))
+            .await
+    }
+
+    /// Fetches a single hero.
+    /// Corresponds to GET /v2/pvp/heroes/{id}
+    pub async fn get_hero(client: &Client, id: &str) -> Result<Hero, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/pvp/heroes/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple heroes.
+    /// Corresponds to GET /v2/pvp/heroes?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_heroes(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Hero>, GetManyError> {
+        get_many(client, "/v2/pvp/heroes", ids).await
+    }
+}
@@ -0,0 +1,47 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Quaggan {
+    /// The quaggan id (e.g. "hoodie-up").
+    pub id: String,
+    /// The url of the quaggan image.
+    pub url: String,
+}
+
+impl Identified for Quaggan {
+    type Id = String;
+
+    fn id(&self) -> &String {
+        &self.id
+    }
+}
+
+/// Fetches all quaggan ids.
+/// Corresponds to GET /v2/quaggans
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/quaggans")).await
+}
+
+/// Fetches all quaggans.
+/// Corresponds to GET /v2/quaggans?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Quaggan>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/quaggans")).await
+}
+
+/// Fetches a single quaggan.
+/// Corresponds to GET /v2/quaggans/{id}
+pub async fn get_quaggan(client: &Client, id: &str) -> Result<Quaggan, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/quaggans/{}", id)))
+        .await
+}
+
+/// Fetches multiple quaggans.
+/// Corresponds to GET /v2/quaggans?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_quaggans(
+    client: &Client,
+    ids: &[String],
+) -> Result<Vec<Quaggan>, GetManyError> {
+    get_many(client, "/v2/quaggans", ids).await
+}
@@ -0,0 +1,56 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Goal {
+    /// The localized text shown while the goal is active.
+    pub active: String,
+    /// The localized text shown once the goal is complete.
+    pub complete: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Quest {
+    /// The quest id, as reported by `characters::get_quests`.
+    pub id: u32,
+    /// The localized name of the quest.
+    pub name: String,
+    /// The minimum level required to start the quest.
+    pub level: u32,
+    /// The story the quest belongs to, see `stories`.
+    pub story: u32,
+    /// The goals of the quest.
+    pub goals: Vec<Goal>,
+}
+
+impl Identified for Quest {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all quest ids.
+/// Corresponds to GET /v2/quests
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/quests")).await
+}
+
+/// Fetches all quests.
+/// Corresponds to GET /v2/quests?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Quest>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/quests")).await
+}
+
+/// Fetches a single quest.
+/// Corresponds to GET /v2/quests/{id}
+pub async fn get_quest(client: &Client, id: u32) -> Result<Quest, client::GetError> {
+    client.get(&client.url(&format!("/v2/quests/{}", id))).await
+}
+
+/// Fetches multiple quests.
+/// Corresponds to GET /v2/quests?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_quests(client: &Client, ids: &[u32]) -> Result<Vec<Quest>, GetManyError> {
+    get_many(client, "/v2/quests", ids).await
+}
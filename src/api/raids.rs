@@ -0,0 +1,65 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventKind {
+    Checkpoint,
+    Boss,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct RaidEvent {
+    /// The event id, as reported by `account::get_raids`.
+    pub id: String,
+    /// Whether the event is a checkpoint or a boss encounter.
+    #[serde(rename = "type")]
+    pub kind: EventKind,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Wing {
+    /// The wing id (e.g. "spirit_vale").
+    pub id: String,
+    /// The events of the wing, in order.
+    pub events: Vec<RaidEvent>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Raid {
+    /// The raid id (e.g. "forsaken_thicket").
+    pub id: String,
+    /// The wings of the raid.
+    pub wings: Vec<Wing>,
+}
+
+impl Identified for Raid {
+    type Id = String;
+
+    fn id(&self) -> &String {
+        &self.id
+    }
+}
+
+/// Fetches all raid ids.
+/// Corresponds to GET /v2/raids
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/raids")).await
+}
+
+/// Fetches all raids.
+/// Corresponds to GET /v2/raids?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Raid>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/raids")).await
+}
+
+/// Fetches a single raid.
+/// Corresponds to GET /v2/raids/{id}
+pub async fn get_raid(client: &Client, id: &str) -> Result<Raid, client::GetError> {
+    client.get(&client.url(&format!("/v2/raids/{}", id))).await
+}
+
+/// Fetches multiple raids.
+/// Corresponds to GET /v2/raids?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_raids(client: &Client, ids: &[String]) -> Result<Vec<Raid>, GetManyError> {
+    get_many(client, "/v2/raids", ids).await
+}
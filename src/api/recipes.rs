@@ -0,0 +1,88 @@
+use super::{client, get_static_chunked, BulkResult, Client, Identified, ItemId};
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Ingredient {
+    /// The ingredient's item id.
+    pub item_id: ItemId,
+    /// The amount of the ingredient one craft takes.
+    pub count: u32,
+}
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Recipe {
+    /// The recipe id.
+    pub id: u32,
+    /// The recipe type, e.g. `Refinement` or `Axe`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// The item the recipe crafts.
+    pub output_item_id: ItemId,
+    /// The amount of the item one craft produces.
+    pub output_item_count: u32,
+    /// How long one craft takes, in milliseconds.
+    pub time_to_craft_ms: u32,
+    /// The crafting disciplines that can craft the recipe.
+    pub disciplines: Vec<String>,
+    /// The discipline rating required to craft the recipe.
+    pub min_rating: u32,
+    /// Flags like `AutoLearned` or `LearnedFromItem`.
+    pub flags: Vec<String>,
+    /// The items one craft takes.
+    pub ingredients: Vec<Ingredient>,
+    /// The chat link of the recipe.
+    pub chat_link: String,
+}
+
+impl Identified for Recipe {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all recipe ids.
+/// Corresponds to GET /v2/recipes
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get_cached(&client.url("/v2/recipes")).await
+}
+
+/// Fetches a single recipe.
+/// Corresponds to GET /v2/recipes/{id}
+pub async fn get_recipe(client: &Client, id: u32) -> Result<Recipe, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/recipes/{}", id)))
+        .await
+}
+
+/// Fetches any number of recipes, split into requests of at most 200 ids. Ids without a
+/// recipe are reported as missing. Served from the client's disk cache, if any.
+/// Corresponds to GET /v2/recipes?ids=...
+pub async fn get_recipes_chunked(
+    client: &Client,
+    ids: &[u32],
+) -> Result<BulkResult<u32, Recipe>, client::GetError> {
+    get_static_chunked(client, "/v2/recipes", ids).await
+}
+
+/// Fetches the ids of the recipes crafting `item_id`.
+/// Corresponds to GET /v2/recipes/search?output={item_id}
+pub async fn search_by_output(
+    client: &Client,
+    item_id: ItemId,
+) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/recipes/search?output={}", item_id)))
+        .await
+}
+
+/// Fetches the ids of the recipes taking `item_id` as an ingredient.
+/// Corresponds to GET /v2/recipes/search?input={item_id}
+pub async fn search_by_input(
+    client: &Client,
+    item_id: ItemId,
+) -> Result<Vec<u32>, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/recipes/search?input={}", item_id)))
+        .await
+}
@@ -0,0 +1,54 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct DyeSlot {
+    /// The default color of the slot, see `colors`.
+    pub color_id: u32,
+    /// The material the slot is dyed as (e.g. "metal", "leather").
+    pub material: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Skiff {
+    /// The skiff skin id.
+    pub id: u32,
+    /// The localized name of the skin.
+    pub name: String,
+    /// The url of the skin's icon.
+    pub icon: String,
+    /// The dye slots of the skin and their default colors.
+    pub dye_slots: Vec<DyeSlot>,
+}
+
+impl Identified for Skiff {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all skiff skin ids.
+/// Corresponds to GET /v2/skiffs
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/skiffs")).await
+}
+
+/// Fetches all skiffs.
+/// Corresponds to GET /v2/skiffs?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Skiff>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/skiffs")).await
+}
+
+/// Fetches a single skiff skin.
+/// Corresponds to GET /v2/skiffs/{id}
+pub async fn get_skiff(client: &Client, id: u32) -> Result<Skiff, client::GetError> {
+    client.get(&client.url(&format!("/v2/skiffs/{}", id))).await
+}
+
+/// Fetches multiple skiffs.
+/// Corresponds to GET /v2/skiffs?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_skiffs(client: &Client, ids: &[u32]) -> Result<Vec<Skiff>, GetManyError> {
+    get_many(client, "/v2/skiffs", ids).await
+}
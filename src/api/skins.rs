@@ -0,0 +1,52 @@
+use super::{client, get_static_chunked, BulkResult, Client, Identified};
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct Skin {
+    /// The skin id.
+    pub id: u32,
+    /// The localized name of the skin.
+    pub name: String,
+    /// The skin type, e.g. `Armor` or `Weapon`.
+    #[serde(rename = "type")]
+    pub kind: String,
+    /// Flags like `ShowInWardrobe` or `NoCost`.
+    pub flags: Vec<String>,
+    /// The races the skin is restricted to, if any.
+    pub restrictions: Vec<String>,
+    /// The url of the skin's icon.
+    pub icon: Option<String>,
+    /// The skin rarity, e.g. `Exotic`.
+    pub rarity: String,
+    /// A localized description of the skin, if any.
+    pub description: Option<String>,
+}
+
+impl Identified for Skin {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all skin ids.
+/// Corresponds to GET /v2/skins
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get_cached(&client.url("/v2/skins")).await
+}
+
+/// Fetches a single skin.
+/// Corresponds to GET /v2/skins/{id}
+pub async fn get_skin(client: &Client, id: u32) -> Result<Skin, client::GetError> {
+    client.get(&client.url(&format!("/v2/skins/{}", id))).await
+}
+
+/// Fetches any number of skins, split into requests of at most 200 ids. Ids without a
+/// skin are reported as missing. Served from the client's disk cache, if any.
+/// Corresponds to GET /v2/skins?ids=...
+pub async fn get_skins_chunked(
+    client: &Client,
+    ids: &[u32],
+) -> Result<BulkResult<u32, Skin>, client::GetError> {
+    get_static_chunked(client, "/v2/skins", ids).await
+}
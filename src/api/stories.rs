@@ -0,0 +1,125 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Chapter {
+    /// The localized name of the chapter.
+    pub name: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Story {
+    /// The story id, as referenced by `quests::Quest::story`.
+    pub id: u32,
+    /// The season the story belongs to, see `stories::seasons`.
+    pub season: String,
+    /// The localized name of the story.
+    pub name: String,
+    /// The localized description of the story.
+    pub description: String,
+    /// The in-game timeline of the story (e.g. "1325 AE").
+    pub timeline: String,
+    /// The minimum level required to start the story.
+    pub level: u32,
+    /// The sort order of the story within its season.
+    pub order: u32,
+    /// The chapters of the story.
+    pub chapters: Vec<Chapter>,
+    /// The races the story is available to. Missing if available to every race.
+    pub races: Option<Vec<String>>,
+    /// Additional flags (e.g. "RequiresUnlock").
+    #[serde(default)]
+    pub flags: Vec<String>,
+}
+
+impl Identified for Story {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all story ids.
+/// Corresponds to GET /v2/stories
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/stories")).await
+}
+
+/// Fetches all stories.
+/// Corresponds to GET /v2/stories?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Story>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/stories")).await
+}
+
+/// Fetches a single story.
+/// Corresponds to GET /v2/stories/{id}
+pub async fn get_story(client: &Client, id: u32) -> Result<Story, client::GetError> {
+    client
+        .get(&client.url(&format!("/v2/stories/{}", id)))
+        .await
+}
+
+/// Fetches multiple stories.
+/// Corresponds to GET /v2/stories?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_stories(client: &Client, ids: &[u32]) -> Result<Vec<Story>, GetManyError> {
+    get_many(client, "/v2/stories", ids).await
+}
+
+/// Definitions for the /v2/stories/seasons endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/stories/seasons
+pub mod seasons {
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Season {
+        /// The season id (a UUID string).
+        pub id: String,
+        /// The localized name of the season.
+        pub name: String,
+        /// The sort order of the season.
+        pub order: u32,
+        /// The story ids of the season, see `stories`.
+        pub stories: Vec<u32>,
+    }
+
+    impl Identified for Season {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches all story season ids.
+    /// Corresponds to GET /v2/stories/seasons
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/stories/seasons")).await
+    }
+
+    /// Fetches all seasons.
+    /// Corresponds to GET /v2/stories/seasons?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Season>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/stories/seasons"))
+            .await
+    }
+
+    /// Fetches a single story season.
+    /// Corresponds to GET /v2/stories/seasons/{id}
+    pub async fn get_season(client: &Client, id: &str) -> Result<Season, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/stories/seasons/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple seasons.
+    /// Corresponds to GET /v2/stories/seasons?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_seasons(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Season>, GetManyError> {
+        get_many(client, "/v2/stories/seasons", ids).await
+    }
+}
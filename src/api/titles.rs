@@ -0,0 +1,47 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Title {
+    /// The title id.
+    pub id: u32,
+    /// The localized name of the title.
+    pub name: String,
+    /// The achievement ids that grant the title.
+    #[serde(default)]
+    pub achievements: Vec<u32>,
+    /// The achievement points required to earn the title, for point-based titles.
+    pub ap_required: Option<u32>,
+}
+
+impl Identified for Title {
+    type Id = u32;
+
+    fn id(&self) -> &u32 {
+        &self.id
+    }
+}
+
+/// Fetches all title ids.
+/// Corresponds to GET /v2/titles
+pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+    client.get(&client.url("/v2/titles")).await
+}
+
+/// Fetches all titles.
+/// Corresponds to GET /v2/titles?ids=all
+pub async fn get_all(client: &Client) -> Result<Vec<Title>, client::GetError> {
+    client.get_all_by_ids_all(&client.url("/v2/titles")).await
+}
+
+/// Fetches a single title.
+/// Corresponds to GET /v2/titles/{id}
+pub async fn get_title(client: &Client, id: u32) -> Result<Title, client::GetError> {
+    client.get(&client.url(&format!("/v2/titles/{}", id))).await
+}
+
+/// Fetches multiple titles.
+/// Corresponds to GET /v2/titles?ids=...
+/// Note: The API limits the number of IDs per request to 200.
+pub async fn get_many_titles(client: &Client, ids: &[u32]) -> Result<Vec<Title>, GetManyError> {
+    get_many(client, "/v2/titles", ids).await
+}
@@ -0,0 +1,81 @@
+use super::{client, Client};
+
+/// A permission (scope) an API key can grant.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "lowercase")]
+pub enum Permission {
+    Account,
+    Builds,
+    Characters,
+    Guilds,
+    Inventories,
+    Progression,
+    Pvp,
+    Tradingpost,
+    Unlocks,
+    Wallet,
+    Wvw,
+}
+
+impl Permission {
+    /// The name the API uses for this permission.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Permission::Account => "account",
+            Permission::Builds => "builds",
+            Permission::Characters => "characters",
+            Permission::Guilds => "guilds",
+            Permission::Inventories => "inventories",
+            Permission::Progression => "progression",
+            Permission::Pvp => "pvp",
+            Permission::Tradingpost => "tradingpost",
+            Permission::Unlocks => "unlocks",
+            Permission::Wallet => "wallet",
+            Permission::Wvw => "wvw",
+        }
+    }
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub enum TokenKind {
+    APIKey,
+    Subtoken,
+}
+
+#[derive(serde::Deserialize, Debug, Clone)]
+pub struct TokenInfo {
+    /// The API key that was requested, or for subtokens, the key they were created from.
+    pub id: String,
+    /// The name given to the API key by the account owner.
+    pub name: String,
+    #[serde(rename = "type")]
+    pub kind: TokenKind,
+    /// The permissions granted to the token.
+    pub permissions: Vec<Permission>,
+    /// When the subtoken expires, as ISO-8601 timestamp.
+    pub expires_at: Option<String>,
+    /// When the subtoken was created, as ISO-8601 timestamp.
+    pub issued_at: Option<String>,
+    /// The endpoints the subtoken is restricted to, if any.
+    pub urls: Option<Vec<String>>,
+}
+
+impl TokenInfo {
+    /// Whether the token grants `permission`.
+    pub fn has_permission(&self, permission: Permission) -> bool {
+        self.permissions.contains(&permission)
+    }
+}
+
+/// Fetches information about the client's API key.
+/// Corresponds to GET /v2/tokeninfo
+/// Requires authentication.
+pub async fn get_tokeninfo(client: &Client) -> Result<TokenInfo, client::GetError> {
+    client.get(&client.url("/v2/tokeninfo")).await
+}
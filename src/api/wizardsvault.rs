@@ -0,0 +1,156 @@
+use super::{client, Client};
+
+#[derive(serde::Deserialize, Debug)]
+pub struct Season {
+    /// The localized title of the current season.
+    pub title: String,
+    /// When the season started (ISO-8601 format string).
+    pub start: String,
+    /// When the season ends (ISO-8601 format string).
+    pub end: String,
+    /// The listing ids available this season, see `wizardsvault::listings`.
+    pub listings: Vec<u32>,
+    /// The objective ids available this season, see `wizardsvault::objectives`.
+    pub objectives: Vec<u32>,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListingKind {
+    Featured,
+    Normal,
+    Legacy,
+}
+
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Track {
+    PvE,
+    PvP,
+    WvW,
+}
+
+/// Fetches the current Wizard's Vault season.
+/// Corresponds to GET /v2/wizardsvault
+pub async fn get_season(client: &Client) -> Result<Season, client::GetError> {
+    client.get(&client.url("/v2/wizardsvault")).await
+}
+
+/// Definitions for the /v2/wizardsvault/listings endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wizardsvault/listings
+pub mod listings {
+    use super::ListingKind;
+    use crate::api::{client, get_many, Client, GetManyError, Identified, ItemId};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Listing {
+        /// The listing id.
+        pub id: u32,
+        /// The item granted by the listing.
+        pub item_id: ItemId,
+        /// The number of items granted per purchase.
+        pub item_count: u32,
+        /// Which section of the vault the listing is shown in.
+        #[serde(rename = "type")]
+        pub kind: ListingKind,
+        /// The cost of a purchase in Astral Acclaim.
+        pub cost: u32,
+    }
+
+    impl Identified for Listing {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all Wizard's Vault listing ids.
+    /// Corresponds to GET /v2/wizardsvault/listings
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/wizardsvault/listings")).await
+    }
+
+    /// Fetches all listings.
+    /// Corresponds to GET /v2/wizardsvault/listings?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Listing>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/wizardsvault/listings"))
+            .await
+    }
+
+    /// Fetches a single Wizard's Vault listing.
+    /// Corresponds to GET /v2/wizardsvault/listings/{id}
+    pub async fn get_listing(client: &Client, id: u32) -> Result<Listing, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wizardsvault/listings/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple listings.
+    /// Corresponds to GET /v2/wizardsvault/listings?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_listings(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<Listing>, GetManyError> {
+        get_many(client, "/v2/wizardsvault/listings", ids).await
+    }
+}
+
+/// Definitions for the /v2/wizardsvault/objectives endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wizardsvault/objectives
+pub mod objectives {
+    use super::Track;
+    use crate::api::{client, get_many, Client, GetManyError, Identified};
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Objective {
+        /// The objective id.
+        pub id: u32,
+        /// The localized title of the objective.
+        pub title: String,
+        /// The game mode the objective belongs to.
+        pub track: Track,
+        /// The Astral Acclaim rewarded for completing the objective.
+        pub acclaim: u32,
+    }
+
+    impl Identified for Objective {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all Wizard's Vault objective ids.
+    /// Corresponds to GET /v2/wizardsvault/objectives
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/wizardsvault/objectives")).await
+    }
+
+    /// Fetches all objectives.
+    /// Corresponds to GET /v2/wizardsvault/objectives?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Objective>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/wizardsvault/objectives"))
+            .await
+    }
+
+    /// Fetches a single Wizard's Vault objective.
+    /// Corresponds to GET /v2/wizardsvault/objectives/{id}
+    pub async fn get_objective(client: &Client, id: u32) -> Result<Objective, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wizardsvault/objectives/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple objectives.
+    /// Corresponds to GET /v2/wizardsvault/objectives?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_objectives(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<Objective>, GetManyError> {
+        get_many(client, "/v2/wizardsvault/objectives", ids).await
+    }
+}
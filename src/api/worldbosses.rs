@@ -0,0 +1,7 @@
+use super::{client, Client};
+
+/// Fetches the ids of all world bosses, as reported by the account world bosses endpoint.
+/// Corresponds to GET /v2/worldbosses
+pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+    client.get(&client.url("/v2/worldbosses")).await
+}
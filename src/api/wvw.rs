@@ -0,0 +1,628 @@
+use super::{client, get_many, Client, GetManyError, Identified};
+use crate::api::guild::GuildId;
+
+/// A value recorded separately for each of the three teams in a match.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TeamValues<T> {
+    pub red: T,
+    pub blue: T,
+    pub green: T,
+}
+
+/// The team owning an objective or bonus.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Team {
+    Red,
+    Blue,
+    Green,
+    Neutral,
+    /// A team added to the API after this crate was written.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kind of WvW map.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapKind {
+    RedHome,
+    BlueHome,
+    GreenHome,
+    Center,
+    EdgeOfTheMists,
+    /// A kind added to the API after this crate was written.
+    #[serde(other)]
+    Unknown,
+}
+
+/// The kind of WvW objective.
+#[derive(serde::Deserialize, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ObjectiveKind {
+    Spawn,
+    Camp,
+    Ruins,
+    Tower,
+    Keep,
+    Castle,
+    Mercenary,
+    Resource,
+    Generic,
+    /// A kind added to the API after this crate was written.
+    #[serde(other)]
+    Unknown,
+}
+
+/// Definitions for the /v2/wvw/matches endpoints.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wvw/matches
+pub mod matches {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MapScore {
+        /// The map the scores were earned on.
+        #[serde(rename = "type")]
+        pub kind: MapKind,
+        /// The score of each team on this map.
+        pub scores: TeamValues<u32>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Skirmish {
+        /// The skirmish id, counting up from 1 over the course of the match.
+        pub id: u32,
+        /// The total score of each team during this skirmish.
+        pub scores: TeamValues<u32>,
+        /// The per-map score of each team during this skirmish.
+        pub map_scores: Vec<MapScore>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MapBonus {
+        /// The bonus type (e.g. "Bloodlust").
+        #[serde(rename = "type")]
+        pub kind: String,
+        /// The team currently holding the bonus.
+        pub owner: Team,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MatchObjective {
+        /// The objective id (e.g. "38-6"), see `wvw::objectives`.
+        pub id: String,
+        /// The kind of objective.
+        #[serde(rename = "type")]
+        pub kind: ObjectiveKind,
+        /// The team currently holding the objective.
+        pub owner: Team,
+        /// When the objective last changed owner (ISO-8601 format string).
+        pub last_flipped: String,
+        /// The guild that has claimed the objective, if any.
+        pub claimed_by: Option<GuildId>,
+        /// When the objective was claimed (ISO-8601 format string).
+        pub claimed_at: Option<String>,
+        /// The points awarded to the owner every tick.
+        pub points_tick: u32,
+        /// The points awarded for capturing the objective.
+        pub points_capture: u32,
+        /// The number of supply caravans delivered to the objective.
+        pub yaks_delivered: Option<u32>,
+        /// The guild upgrade ids slotted into the objective.
+        #[serde(default)]
+        pub guild_upgrades: Vec<u32>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MatchMap {
+        /// The map id.
+        pub id: u32,
+        /// The kind of map.
+        #[serde(rename = "type")]
+        pub kind: MapKind,
+        /// The score of each team on this map.
+        pub scores: TeamValues<u32>,
+        /// The number of kills each team has achieved on this map.
+        pub kills: TeamValues<u32>,
+        /// The number of deaths each team has suffered on this map.
+        pub deaths: TeamValues<u32>,
+        /// The map-wide bonuses currently held.
+        pub bonuses: Vec<MapBonus>,
+        /// The current state of every objective on this map.
+        pub objectives: Vec<MatchObjective>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Match {
+        /// The match id (e.g. "1-1", region-tier).
+        pub id: String,
+        /// When the match started (ISO-8601 format string).
+        pub start_time: String,
+        /// When the match ends (ISO-8601 format string).
+        pub end_time: String,
+        /// The total score of each team.
+        pub scores: TeamValues<u32>,
+        /// The main world id of each team.
+        pub worlds: TeamValues<u32>,
+        /// All world ids (including linked worlds) of each team.
+        pub all_worlds: TeamValues<Vec<u32>>,
+        /// The total number of kills of each team.
+        pub kills: TeamValues<u32>,
+        /// The total number of deaths of each team.
+        pub deaths: TeamValues<u32>,
+        /// The victory points of each team.
+        pub victory_points: TeamValues<u32>,
+        /// Every skirmish of the match so far.
+        pub skirmishes: Vec<Skirmish>,
+        /// The per-map state of the match.
+        pub maps: Vec<MatchMap>,
+    }
+
+    impl Identified for Match {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Overview {
+        /// The match id.
+        pub id: String,
+        /// The main world id of each team.
+        pub worlds: TeamValues<u32>,
+        /// All world ids (including linked worlds) of each team.
+        pub all_worlds: TeamValues<Vec<u32>>,
+        /// When the match started (ISO-8601 format string).
+        pub start_time: String,
+        /// When the match ends (ISO-8601 format string).
+        pub end_time: String,
+    }
+
+    impl Identified for Overview {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Scores {
+        /// The match id.
+        pub id: String,
+        /// The total score of each team.
+        pub scores: TeamValues<u32>,
+        /// The victory points of each team.
+        pub victory_points: TeamValues<u32>,
+        /// Every skirmish of the match so far.
+        pub skirmishes: Vec<Skirmish>,
+        /// The per-map score of each team.
+        pub maps: Vec<MapScore>,
+    }
+
+    impl Identified for Scores {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct MapStats {
+        /// The map id.
+        pub id: u32,
+        /// The kind of map.
+        #[serde(rename = "type")]
+        pub kind: MapKind,
+        /// The number of kills each team has achieved on this map.
+        pub kills: TeamValues<u32>,
+        /// The number of deaths each team has suffered on this map.
+        pub deaths: TeamValues<u32>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Stats {
+        /// The match id.
+        pub id: String,
+        /// The total number of kills of each team.
+        pub kills: TeamValues<u32>,
+        /// The total number of deaths of each team.
+        pub deaths: TeamValues<u32>,
+        /// The per-map kills and deaths.
+        pub maps: Vec<MapStats>,
+    }
+
+    impl Identified for Stats {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches the ids of all currently running matches.
+    /// Corresponds to GET /v2/wvw/matches
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/wvw/matches")).await
+    }
+
+    /// Fetches the full details of a single match.
+    /// Corresponds to GET /v2/wvw/matches/{id}
+    pub async fn get_match(client: &Client, id: &str) -> Result<Match, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches/{}", id)))
+            .await
+    }
+
+    /// Fetches the full details of multiple matches.
+    /// Corresponds to GET /v2/wvw/matches?ids=...
+    pub async fn get_many_matches(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Match>, GetManyError> {
+        get_many(client, "/v2/wvw/matches", ids).await
+    }
+
+    /// Fetches the full details of the match a world is currently playing in.
+    /// Corresponds to GET /v2/wvw/matches?world={world_id}
+    pub async fn get_match_by_world(
+        client: &Client,
+        world_id: u32,
+    ) -> Result<Match, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches?world={}", world_id)))
+            .await
+    }
+
+    /// Fetches the overview of a single match.
+    /// Corresponds to GET /v2/wvw/matches/overview/{id}
+    pub async fn get_overview(client: &Client, id: &str) -> Result<Overview, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches/overview/{}", id)))
+            .await
+    }
+
+    /// Fetches the overviews of multiple matches.
+    /// Corresponds to GET /v2/wvw/matches/overview?ids=...
+    pub async fn get_many_overviews(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Overview>, GetManyError> {
+        get_many(client, "/v2/wvw/matches/overview", ids).await
+    }
+
+    /// Fetches the overview of the match a world is currently playing in.
+    /// Corresponds to GET /v2/wvw/matches/overview?world={world_id}
+    pub async fn get_overview_by_world(
+        client: &Client,
+        world_id: u32,
+    ) -> Result<Overview, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches/overview?world={}", world_id)))
+            .await
+    }
+
+    /// Fetches the scores of a single match.
+    /// Corresponds to GET /v2/wvw/matches/scores/{id}
+    pub async fn get_scores(client: &Client, id: &str) -> Result<Scores, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches/scores/{}", id)))
+            .await
+    }
+
+    /// Fetches the scores of multiple matches.
+    /// Corresponds to GET /v2/wvw/matches/scores?ids=...
+    pub async fn get_many_scores(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Scores>, GetManyError> {
+        get_many(client, "/v2/wvw/matches/scores", ids).await
+    }
+
+    /// Fetches the scores of the match a world is currently playing in.
+    /// Corresponds to GET /v2/wvw/matches/scores?world={world_id}
+    pub async fn get_scores_by_world(
+        client: &Client,
+        world_id: u32,
+    ) -> Result<Scores, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches/scores?world={}", world_id)))
+            .await
+    }
+
+    /// Fetches the kill/death stats of a single match.
+    /// Corresponds to GET /v2/wvw/matches/stats/{id}
+    pub async fn get_stats(client: &Client, id: &str) -> Result<Stats, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches/stats/{}", id)))
+            .await
+    }
+
+    /// Fetches the kill/death stats of multiple matches.
+    /// Corresponds to GET /v2/wvw/matches/stats?ids=...
+    pub async fn get_many_stats(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Stats>, GetManyError> {
+        get_many(client, "/v2/wvw/matches/stats", ids).await
+    }
+
+    /// Fetches the kill/death stats of the match a world is currently playing in.
+    /// Corresponds to GET /v2/wvw/matches/stats?world={world_id}
+    pub async fn get_stats_by_world(
+        client: &Client,
+        world_id: u32,
+    ) -> Result<Stats, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/matches/stats?world={}", world_id)))
+            .await
+    }
+}
+
+/// Definitions for the /v2/wvw/objectives endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wvw/objectives
+pub mod objectives {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Objective {
+        /// The objective id (e.g. "38-6", map id and objective number).
+        pub id: String,
+        /// The localized name of the objective.
+        pub name: String,
+        /// The kind of objective.
+        #[serde(rename = "type")]
+        pub kind: ObjectiveKind,
+        /// The map sector the objective is located in.
+        pub sector_id: u32,
+        /// The map id the objective is located on.
+        pub map_id: u32,
+        /// The kind of map the objective is located on.
+        pub map_type: MapKind,
+        /// The map coordinates of the objective.
+        pub coord: Option<[f64; 3]>,
+        /// The map coordinates of the objective's label.
+        pub label_coord: Option<[f64; 2]>,
+        /// The url of the objective's map marker icon.
+        pub marker: Option<String>,
+        /// The chat link of the objective.
+        pub chat_link: String,
+        /// The id of the objective's upgrade path, see `wvw::upgrades`.
+        pub upgrade_id: Option<u32>,
+    }
+
+    impl Identified for Objective {
+        type Id = String;
+
+        fn id(&self) -> &String {
+            &self.id
+        }
+    }
+
+    /// Fetches all objective ids.
+    /// Corresponds to GET /v2/wvw/objectives
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<String>, client::GetError> {
+        client.get(&client.url("/v2/wvw/objectives")).await
+    }
+
+    /// Fetches all objectives.
+    /// Corresponds to GET /v2/wvw/objectives?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Objective>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/wvw/objectives"))
+            .await
+    }
+
+    /// Fetches a single objective.
+    /// Corresponds to GET /v2/wvw/objectives/{id}
+    pub async fn get_objective(client: &Client, id: &str) -> Result<Objective, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/objectives/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple objectives.
+    /// Corresponds to GET /v2/wvw/objectives?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_objectives(
+        client: &Client,
+        ids: &[String],
+    ) -> Result<Vec<Objective>, GetManyError> {
+        get_many(client, "/v2/wvw/objectives", ids).await
+    }
+}
+
+/// Definitions for the /v2/wvw/ranks endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wvw/ranks
+pub mod ranks {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Rank {
+        /// The rank id.
+        pub id: u32,
+        /// The localized title granted by the rank.
+        pub title: String,
+        /// The minimum WvW level required for the rank.
+        pub min_rank: u32,
+    }
+
+    impl Identified for Rank {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all rank ids.
+    /// Corresponds to GET /v2/wvw/ranks
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/wvw/ranks")).await
+    }
+
+    /// Fetches all ranks.
+    /// Corresponds to GET /v2/wvw/ranks?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Rank>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/wvw/ranks"))
+            .await
+    }
+
+    /// Fetches a single rank.
+    /// Corresponds to GET /v2/wvw/ranks/{id}
+    pub async fn get_rank(client: &Client, id: u32) -> Result<Rank, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/ranks/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple ranks.
+    /// Corresponds to GET /v2/wvw/ranks?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_ranks(client: &Client, ids: &[u32]) -> Result<Vec<Rank>, GetManyError> {
+        get_many(client, "/v2/wvw/ranks", ids).await
+    }
+}
+
+/// Definitions for the /v2/wvw/abilities endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wvw/abilities
+pub mod abilities {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct AbilityRank {
+        /// The WvW ability points required to purchase this rank.
+        pub cost: u32,
+        /// The localized description of the rank's effect.
+        pub effect: String,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Ability {
+        /// The ability id.
+        pub id: u32,
+        /// The localized name of the ability.
+        pub name: String,
+        /// The localized description of the ability.
+        pub description: String,
+        /// The url of the ability's icon.
+        pub icon: String,
+        /// The purchasable ranks of the ability.
+        pub ranks: Vec<AbilityRank>,
+    }
+
+    impl Identified for Ability {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all ability ids.
+    /// Corresponds to GET /v2/wvw/abilities
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/wvw/abilities")).await
+    }
+
+    /// Fetches all abilities.
+    /// Corresponds to GET /v2/wvw/abilities?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Ability>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/wvw/abilities"))
+            .await
+    }
+
+    /// Fetches a single ability.
+    /// Corresponds to GET /v2/wvw/abilities/{id}
+    pub async fn get_ability(client: &Client, id: u32) -> Result<Ability, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/abilities/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple abilities.
+    /// Corresponds to GET /v2/wvw/abilities?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_abilities(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<Ability>, GetManyError> {
+        get_many(client, "/v2/wvw/abilities", ids).await
+    }
+}
+
+/// Definitions for the /v2/wvw/upgrades endpoint.
+/// See: https://wiki.guildwars2.com/wiki/API:2/wvw/upgrades
+pub mod upgrades {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct TierUpgrade {
+        /// The localized name of the upgrade.
+        pub name: String,
+        /// The localized description of the upgrade.
+        pub description: String,
+        /// The url of the upgrade's icon.
+        pub icon: String,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct UpgradeTier {
+        /// The localized name of the tier (e.g. "Secured").
+        pub name: String,
+        /// The number of supply caravans needed to reach the tier.
+        pub yaks_required: u32,
+        /// The upgrades unlocked by reaching the tier.
+        pub upgrades: Vec<TierUpgrade>,
+    }
+
+    #[derive(serde::Deserialize, Debug)]
+    pub struct Upgrade {
+        /// The upgrade id, referenced by `wvw::objectives::Objective::upgrade_id`.
+        pub id: u32,
+        /// The tiers of the upgrade path.
+        pub tiers: Vec<UpgradeTier>,
+    }
+
+    impl Identified for Upgrade {
+        type Id = u32;
+
+        fn id(&self) -> &u32 {
+            &self.id
+        }
+    }
+
+    /// Fetches all upgrade ids.
+    /// Corresponds to GET /v2/wvw/upgrades
+    pub async fn get_all_ids(client: &Client) -> Result<Vec<u32>, client::GetError> {
+        client.get(&client.url("/v2/wvw/upgrades")).await
+    }
+
+    /// Fetches all upgrades.
+    /// Corresponds to GET /v2/wvw/upgrades?ids=all
+    pub async fn get_all(client: &Client) -> Result<Vec<Upgrade>, client::GetError> {
+        client
+            .get_all_by_ids_all(&client.url("/v2/wvw/upgrades"))
+            .await
+    }
+
+    /// Fetches a single upgrade.
+    /// Corresponds to GET /v2/wvw/upgrades/{id}
+    pub async fn get_upgrade(client: &Client, id: u32) -> Result<Upgrade, client::GetError> {
+        client
+            .get(&client.url(&format!("/v2/wvw/upgrades/{}", id)))
+            .await
+    }
+
+    /// Fetches multiple upgrades.
+    /// Corresponds to GET /v2/wvw/upgrades?ids=...
+    /// Note: The API limits the number of IDs per request to 200.
+    pub async fn get_many_upgrades(
+        client: &Client,
+        ids: &[u32],
+    ) -> Result<Vec<Upgrade>, GetManyError> {
+        get_many(client, "/v2/wvw/upgrades", ids).await
+    }
+}
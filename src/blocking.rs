@@ -0,0 +1,175 @@
+//! A blocking client, for scripts and plugins that can't run an async runtime.
+//!
+//! [`Client`] wraps the async [`client::Client`] together with a private single-threaded
+//! runtime, so every model type and endpoint in [`crate::api`] is shared with the async
+//! path. Endpoint functions are called through [`Client::call`]:
+//!
+//! ```no_run
+//! use gw2gd::{api, blocking::Client};
+//!
+//! let client = Client::new(None)?;
+//! let ids = client.call(|client| api::prices::get_all_ids(client))?;
+//! # Ok::<_, Box<dyn std::error::Error>>(())
+//! ```
+//!
+//! Methods of this client must not be called from within an async runtime.
+
+use std::{borrow::Cow, future::Future};
+
+use serde::de::DeserializeOwned;
+
+use crate::client::{
+    self, GetError, Language, NewClientError, Paginated, PaginatedGetError, PaginationParams,
+    RawResponse,
+};
+
+/// Error type for creating a blocking client.
+#[derive(thiserror::Error, Debug)]
+pub enum NewBlockingClientError {
+    #[error("failed to create client: {0}")]
+    Client(#[from] NewClientError),
+    #[error("failed to start runtime: {0}")]
+    Runtime(#[from] std::io::Error),
+}
+
+/// A blocking client for interacting with the Guild Wars 2 API.
+#[derive(Debug)]
+pub struct Client {
+    inner: client::Client,
+    runtime: tokio::runtime::Runtime,
+}
+
+impl Client {
+    /// Creates a new blocking API client. See [`client::Client::new`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client or the runtime cannot be built, or if the token
+    /// is invalid for the header.
+    pub fn new(token: Option<Cow<'static, str>>) -> Result<Self, NewBlockingClientError> {
+        Ok(Self::from_async(client::Client::new(token)?)?)
+    }
+
+    /// Wraps a configured async client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runtime cannot be started.
+    pub fn from_async(inner: client::Client) -> Result<Self, std::io::Error> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+
+        Ok(Self { inner, runtime })
+    }
+
+    /// The async client requests are made with.
+    pub fn as_async(&self) -> &client::Client {
+        &self.inner
+    }
+
+    /// Runs an async endpoint function to completion, e.g.
+    /// `client.call(|client| api::items::get_item(client, 19721))`.
+    pub fn call<'a, F, Fut>(&'a self, f: F) -> Fut::Output
+    where
+        F: FnOnce(&'a client::Client) -> Fut,
+        Fut: Future,
+    {
+        self.runtime.block_on(f(&self.inner))
+    }
+
+    /// Blocking version of [`client::Client::get`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    pub fn get<Response>(&self, url: &str) -> Result<Response, GetError>
+    where
+        Response: DeserializeOwned,
+    {
+        self.call(|client| client.get(url))
+    }
+
+    /// Blocking version of [`client::Client::get_with_token`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for invalid tokens, network issues or non-successful
+    /// API responses.
+    pub fn get_with_token<Response>(&self, url: &str, token: &str) -> Result<Response, GetError>
+    where
+        Response: DeserializeOwned,
+    {
+        self.call(|client| client.get_with_token(url, token))
+    }
+
+    /// Blocking version of [`client::Client::get_raw`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    pub fn get_raw(&self, url: &str) -> Result<RawResponse, GetError> {
+        self.call(|client| client.get_raw(url))
+    }
+
+    /// Blocking version of [`client::Client::get_localized`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    pub fn get_localized<Response>(
+        &self,
+        url: &str,
+        language: Language,
+    ) -> Result<Response, GetError>
+    where
+        Response: DeserializeOwned,
+    {
+        self.call(|client| client.get_localized(url, language))
+    }
+
+    /// Blocking version of [`client::Client::get_paginated`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaginatedGetError` variants for network issues, non-successful API responses,
+    /// missing or invalid pagination headers, or JSON deserialization failures.
+    pub fn get_paginated<Response>(
+        &self,
+        base_url: &str,
+        params: PaginationParams,
+    ) -> Result<Paginated<Response>, PaginatedGetError>
+    where
+        Response: DeserializeOwned,
+    {
+        self.call(|client| client.get_paginated(base_url, params))
+    }
+
+    /// Blocking version of [`client::Client::get_all_pages`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaginatedGetError` if any of the underlying page requests fail.
+    pub fn get_all_pages<Item>(
+        &self,
+        base_url: &str,
+        params: PaginationParams,
+    ) -> Result<Vec<Item>, PaginatedGetError>
+    where
+        Vec<Item>: DeserializeOwned,
+    {
+        self.call(|client| client.get_all_pages(base_url, params))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn call_runs_future_to_completion() {
+        let client = Client::new(None).unwrap();
+        let value = client.call(|_| async { 42 });
+        assert_eq!(value, 42);
+    }
+}
@@ -1,44 +1,123 @@
-use std::{borrow::Cow, fmt, str::FromStr};
-
-use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, USER_AGENT};
+use std::{
+    borrow::Cow,
+    collections::{HashMap, VecDeque},
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use bytes::Bytes;
+use futures_util::{stream, Stream, TryStreamExt};
+use reqwest::header::{
+    HeaderMap, HeaderValue, AUTHORIZATION, ETAG, IF_NONE_MATCH, LINK, RETRY_AFTER, USER_AGENT,
+};
 use serde::de::DeserializeOwned;
 
+use crate::{
+    api::tokeninfo::{Permission, TokenInfo},
+    runtime::{self, Instant},
+};
+
+pub use api_error::ApiError;
+pub use auth::{AuthProvider, SubtokenRotation};
+pub use disk_cache::DiskCache;
+pub use fixtures::{RecordingTransport, ReplayTransport};
+pub use metrics::Metrics;
+pub use middleware::Middleware;
+pub use multi::MultiClient;
+pub use schema::{SchemaWarning, SchemaWarningKind};
+pub use tokio_util::sync::CancellationToken;
+pub use transport::{ReqwestTransport, Transport, TransportError};
+
+pub mod api_error;
+pub mod auth;
+pub mod disk_cache;
+pub mod fixtures;
+pub mod metrics;
+pub mod middleware;
+pub mod multi;
+pub mod schema;
+pub mod transport;
+
 pub const DEFAULT_PAGE_SIZE: usize = 200;
 
+/// The official API host, used unless [`ClientBuilder::base_url`] is set.
+pub const DEFAULT_BASE_URL: &str = "https://api.guildwars2.com";
+
+/// Identifies this crate in the `User-Agent` header, e.g. `gw2gd/0.1.0`.
+pub const CRATE_USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// How long [`Client::get_cached`] trusts the last known game build before checking
+/// /v2/build again, so long-lived clients notice game updates.
+const BUILD_ID_MAX_AGE: Duration = Duration::from_secs(5 * 60);
+
 /// Error type for non-paginated `get` requests.
 #[derive(thiserror::Error, Debug)]
 pub enum NewClientError {
     #[error("HTTP request error: {0}")]
     Http(#[from] reqwest::Error),
-    #[error("failed to build reqwest client: {0}")]
+    #[error("invalid header value: {0}")]
     InvalidToken(#[from] reqwest::header::InvalidHeaderValue),
+    #[error("failed to create fixture directory: {0}")]
+    Fixtures(#[from] std::io::Error),
+    #[error("invalid base url: {0}")]
+    InvalidBaseUrl(String),
 }
 
 /// Error type for non-paginated `get` requests.
 #[derive(thiserror::Error, Debug)]
 pub enum GetError {
     #[error("HTTP request error: {0}")]
-    Http(#[from] reqwest::Error),
+    Http(#[from] TransportError),
 
-    #[error("Request failed: status {status}, url: {url}, body: {body}")]
-    RequestFailedWithBody {
+    #[error("Request failed: status {status}, url: {url}: {error}")]
+    Api {
         status: reqwest::StatusCode,
         url: String,
-        body: String,
+        error: ApiError,
     },
+
+    #[error("Failed to deserialize response body: {0}")]
+    DeserializationError(#[from] serde_json::Error),
+
+    #[error("invalid API key: {0}")]
+    InvalidToken(reqwest::header::InvalidHeaderValue),
+}
+
+impl GetError {
+    /// The error reported by the API, if it answered with an error status.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            GetError::Api { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// Error type for [`Client::verify_permissions`].
+#[derive(thiserror::Error, Debug)]
+pub enum VerifyPermissionsError {
+    #[error("no API key configured")]
+    MissingToken,
+    #[error("API key is missing permissions: {}", .0.iter().map(|p| p.name()).collect::<Vec<_>>().join(", "))]
+    MissingPermissions(Vec<Permission>),
+    #[error("client error: {0}")]
+    ClientError(#[from] GetError),
 }
 
 /// Error type for paginated `get_paginated` requests.
 #[derive(thiserror::Error, Debug)]
 pub enum PaginatedGetError {
     #[error("HTTP request error: {0}")]
-    Http(reqwest::Error), // Don't use #[from] here to distinguish source easily
+    Http(TransportError), // Don't use #[from] here to distinguish source easily
 
-    #[error("Request failed: status {status}, url: {url}, body: {body}")]
-    RequestFailedWithBody {
+    #[error("Request failed: status {status}, url: {url}: {error}")]
+    Api {
         status: reqwest::StatusCode,
         url: String,
-        body: String,
+        error: ApiError,
     },
 
     #[error("Failed to parse pagination header '{header_name}': {source}")]
@@ -51,28 +130,393 @@ pub enum PaginatedGetError {
     MissingHeaderError { header_name: String },
 
     #[error("Failed to deserialize response body: {0}")]
-    DeserializationError(reqwest::Error), // Capture the specific deserialization error
+    DeserializationError(serde_json::Error), // Capture the specific deserialization error
+
+    #[error("Deadline of {0:?} exceeded")]
+    DeadlineExceeded(Duration),
+
+    #[error("Cancelled")]
+    Cancelled,
+
+    #[error("Failed to authenticate: {0}")]
+    Auth(Box<GetError>),
+}
+
+/// A multi-page request that failed part way, with the items fetched until then.
+#[derive(thiserror::Error, Debug)]
+#[error("failed after fetching {} items: {error}", .items.len())]
+pub struct PartialResultError<Item> {
+    /// Items of the pages fetched before the failure.
+    pub items: Vec<Item>,
+    #[source]
+    pub error: PaginatedGetError,
+}
+
+impl PaginatedGetError {
+    /// The error reported by the API, if it answered with an error status.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            PaginatedGetError::Api { error, .. } => Some(error),
+            _ => None,
+        }
+    }
+}
+
+/// A successful response with its body fully read, see [`Client::get_raw`].
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    pub status: reqwest::StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+/// Responses carrying an `ETag`, keyed by url. Used to make conditional requests that
+/// the API can answer with 304 Not Modified instead of sending the body again.
+#[derive(Debug, Default)]
+struct ETagCache {
+    entries: Mutex<HashMap<String, RawResponse>>,
+}
+
+impl ETagCache {
+    fn etag(&self, url: &str) -> Option<HeaderValue> {
+        let entries = self.entries.lock().expect("etag cache lock poisoned");
+        entries.get(url)?.headers.get(ETAG).cloned()
+    }
+
+    fn get(&self, url: &str) -> Option<RawResponse> {
+        let entries = self.entries.lock().expect("etag cache lock poisoned");
+        entries.get(url).cloned()
+    }
+
+    fn insert(&self, url: &str, response: &RawResponse) {
+        if response.headers.contains_key(ETAG) {
+            let mut entries = self.entries.lock().expect("etag cache lock poisoned");
+            entries.insert(url.to_string(), response.clone());
+        }
+    }
+
+    fn clear(&self) {
+        self.entries
+            .lock()
+            .expect("etag cache lock poisoned")
+            .clear();
+    }
+}
+
+/// Requests are only coalesced if they are for the same url with the same API key.
+type InFlightKey = (String, Option<HeaderValue>);
+
+/// The span a request is sent in, with fields describing it. Spans are only created with
+/// the `tracing` feature.
+#[cfg(feature = "tracing")]
+fn request_span(url: &str) -> tracing::Span {
+    let query = url.split_once('?').map_or("", |(_, query)| query);
+    let param = |name: &str| {
+        query
+            .split('&')
+            .find_map(|pair| pair.strip_prefix(name)?.strip_prefix('='))
+    };
+
+    tracing::info_span!(
+        "gw2_request",
+        endpoint = %metrics::endpoint_path(url),
+        ids = param("ids").filter(|ids| *ids != "all").map(|ids| ids.split(',').count()),
+        page = param("page").and_then(|page| page.parse::<u64>().ok()),
+        status = tracing::field::Empty,
+        attempts = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    )
+}
+
+#[cfg(not(feature = "tracing"))]
+fn request_span(_url: &str) -> tracing::Span {
+    tracing::Span::none()
+}
+
+/// Failure of a single request, after retries were exhausted.
+enum SendError {
+    /// The client's [`AuthProvider`] failed.
+    Auth(GetError),
+    Http(TransportError),
+    Status {
+        status: reqwest::StatusCode,
+        url: String,
+        body: String,
+    },
+}
+
+impl From<SendError> for GetError {
+    fn from(err: SendError) -> Self {
+        match err {
+            SendError::Auth(e) => e,
+            SendError::Http(e) => GetError::Http(e),
+            SendError::Status { status, url, body } => GetError::Api {
+                status,
+                url,
+                error: ApiError::from_response(status, &body),
+            },
+        }
+    }
+}
+
+impl From<SendError> for PaginatedGetError {
+    fn from(err: SendError) -> Self {
+        match err {
+            SendError::Auth(e) => PaginatedGetError::Auth(Box::new(e)),
+            SendError::Http(e) => PaginatedGetError::Http(e),
+            SendError::Status { status, url, body } => PaginatedGetError::Api {
+                status,
+                url,
+                error: ApiError::from_response(status, &body),
+            },
+        }
+    }
+}
+
+/// Which kinds of transient failures are retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOn {
+    /// Retry on 5xx responses.
+    pub server_errors: bool,
+    /// Retry when the connection could not be established or was interrupted.
+    pub network_errors: bool,
+    /// Retry when the request timed out.
+    pub timeouts: bool,
+}
+
+/// Retry policy for transient failures, using exponential backoff between attempts.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    /// Maximum number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry, doubled for every subsequent retry.
+    pub base_delay: Duration,
+    /// Upper bound for the delay between two attempts.
+    pub max_delay: Duration,
+    /// Randomize each delay between 50% and 100% of its nominal value, so concurrent
+    /// consumers don't retry in lockstep.
+    pub jitter: bool,
+    /// Which failures are retried.
+    pub retry_on: RetryOn,
+    /// Maximum number of times a 429 (Too Many Requests) response is waited out and retried.
+    /// These retries don't count towards `max_attempts`. `0` surfaces 429s as errors.
+    pub max_rate_limited_retries: u32,
+    /// On a 429, also drain the client's rate limiter for the requested delay, so other
+    /// requests made through the client slow down as well.
+    pub rate_limit_feedback: bool,
+    /// Limits how many retries of transient failures all requests of the client may make
+    /// together, so a burst of errors during parallel fetches doesn't turn into a burst of
+    /// retries. Waiting out 429 responses is not limited by the budget.
+    pub budget: Option<RetryBudget>,
+}
+
+/// At most `max_retries` retries within any `window`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryBudget {
+    pub max_retries: u32,
+    pub window: Duration,
+}
+
+impl Default for RetryPolicy {
+    /// Defaults to 3 attempts starting at 500ms, retrying all transient failures.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(10),
+            jitter: true,
+            retry_on: RetryOn {
+                server_errors: true,
+                network_errors: true,
+                timeouts: true,
+            },
+            max_rate_limited_retries: 10,
+            rate_limit_feedback: true,
+            budget: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries, including on 429 responses.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            max_rate_limited_retries: 0,
+            ..Default::default()
+        }
+    }
+
+    /// The delay before the given retry (0-indexed).
+    pub fn delay(&self, retry: u32) -> Duration {
+        let nominal = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+
+        if self.jitter {
+            nominal.mul_f64(rand::Rng::gen_range(&mut rand::thread_rng(), 0.5..=1.0))
+        } else {
+            nominal
+        }
+    }
+
+    fn should_retry_status(&self, status: reqwest::StatusCode) -> bool {
+        self.retry_on.server_errors && status.is_server_error()
+    }
+
+    fn should_retry_error(&self, err: &TransportError) -> bool {
+        (self.retry_on.timeouts && err.is_timeout())
+            || (self.retry_on.network_errors && err.is_connect())
+    }
+}
+
+/// The state of the API, see [`Client::health_check`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Health {
+    /// The API answered successfully, after `latency`.
+    Up { latency: Duration },
+    /// The API is unreachable or answered with an unexpected error, described by the
+    /// string.
+    Degraded(String),
+    /// The API is disabled, e.g. during a game update, with the message it answered with.
+    Maintenance(String),
+}
+
+impl Health {
+    /// Whether requests can be expected to succeed.
+    pub fn is_up(&self) -> bool {
+        matches!(self, Health::Up { .. })
+    }
+}
+
+/// A language the API can localize names and descriptions in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Language {
+    English,
+    Spanish,
+    German,
+    French,
+    Chinese,
+}
+
+impl Language {
+    /// The code used for the `lang` query parameter.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::German => "de",
+            Language::French => "fr",
+            Language::Chinese => "zh",
+        }
+    }
+}
+
+impl fmt::Display for Language {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// Endpoints returning no localized text, which `lang` is not sent to.
+const NON_LOCALIZED_ENDPOINTS: &[&str] = &[
+    "/v2/account",
+    "/v2/build",
+    "/v2/commerce",
+    "/v2/createsubtoken",
+    "/v2/tokeninfo",
+];
+
+/// Whether `url` points to one of [`NON_LOCALIZED_ENDPOINTS`] or below.
+fn is_localized(url: &str) -> bool {
+    let Ok(url) = reqwest::Url::parse(url) else {
+        return true;
+    };
+    let path = url.path();
+    !NON_LOCALIZED_ENDPOINTS.iter().any(|endpoint| {
+        path.strip_prefix(endpoint)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+    })
+}
+
+/// Appends the `lang` query parameter to `url`, unless it already specifies one or the
+/// endpoint isn't localized.
+fn localize(url: &str, language: Option<Language>) -> Cow<'_, str> {
+    match language {
+        Some(language) if !url.contains("lang=") && is_localized(url) => {
+            let separator = if url.contains('?') { '&' } else { '?' };
+            Cow::Owned(format!("{}{}lang={}", url, separator, language))
+        }
+        _ => Cow::Borrowed(url),
+    }
+}
+
+/// Parses a `Retry-After` header given in seconds.
+fn parse_retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let seconds = headers
+        .get(RETRY_AFTER)?
+        .to_str()
+        .ok()?
+        .trim()
+        .parse::<f64>()
+        .ok()?;
+
+    (seconds.is_finite() && seconds >= 0.0).then(|| Duration::from_secs_f64(seconds))
+}
+
+/// Whether a rate limit hint header (`X-RateLimit-Remaining`, as sent by some proxies)
+/// reports that no requests are left.
+fn rate_limit_exhausted(headers: &HeaderMap) -> bool {
+    headers
+        .get("X-RateLimit-Remaining")
+        .and_then(|remaining| remaining.to_str().ok()?.trim().parse::<f64>().ok())
+        .is_some_and(|remaining| remaining <= 0.0)
 }
 
 /// A client for interacting with the Guild Wars 2 API.
 pub struct Client {
-    inner: reqwest::Client,
-    #[allow(unused)]
+    transport: Box<dyn Transport>,
+    middleware: Vec<Box<dyn Middleware>>,
+    auth: Option<Box<dyn AuthProvider>>,
+    metrics: metrics::Recorder,
+    /// Sent with every request: user agent and authorization.
+    headers: HeaderMap,
     token: Option<Cow<'static, str>>,
-    rate_limiter: rate_limiter::RateLimiter,
+    /// Validated when building, so urls built on it always parse.
+    base_url: reqwest::Url,
+    rate_limiter: Arc<rate_limiter::RateLimiter>,
+    priority: rate_limiter::Priority,
+    retry_policy: RetryPolicy,
+    etag_cache: Option<ETagCache>,
+    language: Option<Language>,
+    token_info: tokio::sync::OnceCell<TokenInfo>,
+    in_flight: Option<Mutex<HashMap<InFlightKey, Arc<tokio::sync::OnceCell<RawResponse>>>>>,
+    disk_cache: Option<DiskCache>,
+    /// The current game build, and when it was checked.
+    build_id: tokio::sync::Mutex<Option<(u32, Instant)>>,
+    request_timeout: Option<Duration>,
+    /// Caps the number of requests sent at once.
+    concurrency_limit: Option<tokio::sync::Semaphore>,
+    /// When recent retries happened, for enforcing the retry budget.
+    recent_retries: Mutex<VecDeque<Instant>>,
+    /// Collects schema warnings if strict deserialization is enabled.
+    schema: Option<schema::Recorder>,
 }
 
 impl fmt::Debug for Client {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("Client")
-            .field("inner", &self.inner)
+            .field("base_url", &self.base_url())
+            .field("transport", &self.transport)
+            .field("middleware", &self.middleware)
             .field("token", &self.token.as_ref().map(|_| Cow::Borrowed("****"))) // Avoid logging token
             .finish()
     }
 }
 
 impl Client {
-    /// Creates a new API client.
+    /// Creates a new API client with default connection settings.
     ///
     /// # Arguments
     ///
@@ -82,24 +526,454 @@ impl Client {
     ///
     /// Returns an error if the HTTP client cannot be built or if the token is invalid for the header.
     pub fn new(token: Option<Cow<'static, str>>) -> Result<Self, NewClientError> {
-        let mut headers = HeaderMap::new();
-        headers.insert(USER_AGENT, HeaderValue::from_static("gw2gd")); // Example user agent
+        ClientBuilder {
+            token,
+            ..Default::default()
+        }
+        .build()
+    }
 
-        if let Some(token_ref) = &token {
-            let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", token_ref))?;
-            auth_value.set_sensitive(true); // Mark the token as sensitive
-            headers.insert(AUTHORIZATION, auth_value);
+    /// Creates a builder to configure timeouts, proxy and connection settings.
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::default()
+    }
+
+    /// The host requests of [`crate::api`] are sent to, without a trailing slash.
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_str().trim_end_matches('/')
+    }
+
+    /// Builds the url of an API endpoint on the client's host, e.g. `/v2/items`.
+    pub fn url(&self, endpoint: &str) -> String {
+        format!("{}{}", self.base_url(), endpoint)
+    }
+
+    /// Information about the client's API key, fetched from /v2/tokeninfo on first use and
+    /// kept for the lifetime of the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    pub async fn token_info(&self) -> Result<&TokenInfo, GetError> {
+        self.token_info
+            .get_or_try_init(|| crate::api::tokeninfo::get_tokeninfo(self))
+            .await
+    }
+
+    /// Checks that the client's API key grants all of `permissions`, so authenticated
+    /// endpoints can fail fast with a clear error instead of a 403 from the API. Only the
+    /// first call makes a request, see [`Client::token_info`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `VerifyPermissionsError::MissingPermissions` listing every permission the key
+    /// lacks, `MissingToken` if the client has no key, or the error of the tokeninfo request.
+    pub async fn verify_permissions(
+        &self,
+        permissions: &[Permission],
+    ) -> Result<(), VerifyPermissionsError> {
+        if self.token.is_none() {
+            return Err(VerifyPermissionsError::MissingToken);
         }
 
-        let inner = reqwest::ClientBuilder::new()
-            .default_headers(headers)
-            .build()?;
+        let token_info = self.token_info().await?;
+        let missing: Vec<Permission> = permissions
+            .iter()
+            .copied()
+            .filter(|permission| !token_info.has_permission(*permission))
+            .collect();
 
-        Ok(Self {
-            inner,
-            token,
-            rate_limiter: rate_limiter::RateLimiter::new(300, 5.0),
-        })
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(VerifyPermissionsError::MissingPermissions(missing))
+        }
+    }
+
+    /// Probes /v2/build to find out whether the API is usable, so daemons can pause
+    /// polling during maintenance. The probe is rate-limited but never retried or cached.
+    pub async fn health_check(&self) -> Health {
+        self.rate_limiter
+            .acquire_with_priority(1, self.priority)
+            .await;
+
+        let started = Instant::now();
+        let request = transport::Request {
+            url: self.url("/v2/build"),
+            headers: self.headers.clone(),
+        };
+
+        match self.dispatch(request).await {
+            Ok(response) if response.status.is_success() => Health::Up {
+                latency: started.elapsed(),
+            },
+            Ok(response) => {
+                let body = String::from_utf8_lossy(&response.body);
+                match ApiError::from_response(response.status, &body) {
+                    ApiError::Maintenance(text) => Health::Maintenance(text),
+                    error => Health::Degraded(error.to_string()),
+                }
+            }
+            Err(e) => Health::Degraded(e.to_string()),
+        }
+    }
+
+    /// The rate limiter requests wait for, to share it with other clients through
+    /// [`ClientBuilder::rate_limiter`].
+    pub fn rate_limiter(&self) -> &Arc<rate_limiter::RateLimiter> {
+        &self.rate_limiter
+    }
+
+    /// A snapshot of the requests made so far: counts, errors and latencies per endpoint,
+    /// and time spent waiting for the rate limiter.
+    pub fn metrics(&self) -> Metrics {
+        self.metrics.snapshot()
+    }
+
+    /// Resets all metrics to zero.
+    pub fn reset_metrics(&self) {
+        self.metrics.reset()
+    }
+
+    /// Sets the language names and descriptions are returned in, see
+    /// [`ClientBuilder::language`].
+    pub fn with_language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Enables or disables conditional requests, see [`ClientBuilder::conditional_requests`].
+    /// Disabling them drops the responses kept so far.
+    pub fn with_conditional_requests(mut self, enabled: bool) -> Self {
+        self.etag_cache = enabled.then(ETagCache::default);
+        self
+    }
+
+    /// Takes the schema warnings reported since the last call. Always empty unless
+    /// [`ClientBuilder::strict_schema`] is enabled.
+    pub fn take_schema_warnings(&self) -> Vec<SchemaWarning> {
+        self.schema
+            .as_ref()
+            .map(schema::Recorder::take)
+            .unwrap_or_default()
+    }
+
+    /// Deserializes the body of a response to `url`, checking it against `Response` in
+    /// strict mode.
+    fn parse<Response: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &[u8],
+    ) -> Result<Response, serde_json::Error> {
+        match &self.schema {
+            Some(schema) => schema.parse(url, body),
+            None => serde_json::from_slice(body),
+        }
+    }
+
+    /// Drops every response kept for conditional requests.
+    pub fn clear_conditional_cache(&self) {
+        if let Some(cache) = &self.etag_cache {
+            cache.clear();
+        }
+    }
+
+    /// Sets the policy used to retry transient failures, see
+    /// [`ClientBuilder::retry_policy`].
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Records a retry if the retry budget allows another one.
+    fn spend_retry(&self) -> bool {
+        let Some(budget) = self.retry_policy.budget else {
+            return true;
+        };
+
+        let now = Instant::now();
+        let mut recent = self
+            .recent_retries
+            .lock()
+            .expect("retry budget lock poisoned");
+        while recent
+            .front()
+            .is_some_and(|retried| now.duration_since(*retried) >= budget.window)
+        {
+            recent.pop_front();
+        }
+
+        if recent.len() >= budget.max_retries as usize {
+            return false;
+        }
+        recent.push_back(now);
+        true
+    }
+
+    /// Sends a single request through the middleware and the transport.
+    async fn dispatch(
+        &self,
+        request: transport::Request,
+    ) -> Result<transport::Response, TransportError> {
+        let started = Instant::now();
+        let url = request.url.clone();
+        let result = self.dispatch_through_middleware(request).await;
+
+        let failed = match &result {
+            Ok(response) => {
+                !(response.status.is_success()
+                    || response.status == reqwest::StatusCode::NOT_MODIFIED)
+            }
+            Err(_) => true,
+        };
+        self.metrics.record_request(&url, started, failed);
+
+        result
+    }
+
+    async fn send_with_timeout(
+        &self,
+        request: transport::Request,
+    ) -> Result<transport::Response, TransportError> {
+        let Some(timeout) = self.request_timeout else {
+            return self.transport.send(request).await;
+        };
+
+        runtime::timeout(timeout, self.transport.send(request))
+            .await
+            .unwrap_or_else(|_| {
+                Err(TransportError::Timeout(
+                    format!("no response within {:?}", timeout).into(),
+                ))
+            })
+    }
+
+    async fn dispatch_through_middleware(
+        &self,
+        mut request: transport::Request,
+    ) -> Result<transport::Response, TransportError> {
+        if self.middleware.is_empty() {
+            return self.send_with_timeout(request).await;
+        }
+
+        for layer in &self.middleware {
+            layer.on_request(&mut request);
+        }
+
+        let mut result = self.send_with_timeout(request.clone()).await;
+
+        for layer in self.middleware.iter().rev() {
+            match &mut result {
+                Ok(response) => layer.on_response(&request, response),
+                Err(e) => layer.on_error(&request, e),
+            }
+        }
+
+        result
+    }
+
+    /// Sends a GET request, sharing the response with concurrent requests to the same url
+    /// if coalescing is enabled. `authorization` overrides the client's API key.
+    async fn send(
+        &self,
+        url: &str,
+        authorization: Option<&HeaderValue>,
+    ) -> Result<RawResponse, SendError> {
+        use tracing::Instrument;
+
+        let span = request_span(url);
+        let started = Instant::now();
+        let result = self
+            .send_coalesced(url, authorization)
+            .instrument(span.clone())
+            .await;
+        span.record("latency_ms", started.elapsed().as_millis() as u64);
+
+        result
+    }
+
+    async fn send_coalesced(
+        &self,
+        url: &str,
+        authorization: Option<&HeaderValue>,
+    ) -> Result<RawResponse, SendError> {
+        let Some(in_flight) = &self.in_flight else {
+            return self.send_uncoalesced(url, authorization).await;
+        };
+
+        let key = (url.to_string(), authorization.cloned());
+        let cell = in_flight
+            .lock()
+            .expect("in-flight lock poisoned")
+            .entry(key.clone())
+            .or_default()
+            .clone();
+
+        // Concurrent callers wait for the first one's request. If it fails or is
+        // cancelled, the next waiter sends its own.
+        let result = cell
+            .get_or_try_init(|| self.send_uncoalesced(url, authorization))
+            .await
+            .cloned();
+
+        let mut in_flight = in_flight.lock().expect("in-flight lock poisoned");
+        if in_flight
+            .get(&key)
+            .is_some_and(|current| Arc::ptr_eq(current, &cell))
+        {
+            in_flight.remove(&key);
+        }
+
+        result
+    }
+
+    /// Sends a rate-limited GET request, retrying transient failures according to the
+    /// retry policy. 429 responses are retried after the delay the API asks for, without
+    /// counting towards `max_attempts`. Returns the response if it has a success status.
+    /// Requests with an overriding `authorization` bypass the ETag cache, which is shared
+    /// by every request of the client. Otherwise, the client's [`AuthProvider`] is asked
+    /// for the authorization before every attempt.
+    async fn send_uncoalesced(
+        &self,
+        url: &str,
+        authorization: Option<&HeaderValue>,
+    ) -> Result<RawResponse, SendError> {
+        let etag_cache = self.etag_cache.as_ref().filter(|_| authorization.is_none());
+
+        let mut attempt = 1;
+        let mut rate_limited_retries = 0;
+
+        loop {
+            let waiting = Instant::now();
+            self.rate_limiter
+                .acquire_with_priority(1, self.priority)
+                .await;
+            self.metrics.record_rate_limiter_wait(waiting.elapsed());
+
+            let mut request = transport::Request {
+                url: url.to_string(),
+                headers: self.headers.clone(),
+            };
+
+            let authorization = match (authorization, &self.auth) {
+                (Some(authorization), _) => Some(authorization.clone()),
+                (None, Some(auth)) => auth.authorization(self).await.map_err(SendError::Auth)?,
+                (None, None) => None,
+            };
+            if let Some(authorization) = authorization {
+                request.headers.insert(AUTHORIZATION, authorization);
+            }
+            if let Some(etag) = etag_cache.and_then(|cache| cache.etag(url)) {
+                request.headers.insert(IF_NONE_MATCH, etag);
+            }
+
+            let response = {
+                let _permit = match &self.concurrency_limit {
+                    Some(limit) => Some(limit.acquire().await.expect("semaphore is never closed")),
+                    None => None,
+                };
+                self.dispatch(request).await
+            };
+
+            let span = tracing::Span::current();
+            span.record("attempts", attempt);
+            if let Ok(response) = &response {
+                span.record("status", response.status.as_u16());
+
+                if response.status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                    || rate_limit_exhausted(&response.headers)
+                {
+                    self.rate_limiter.slow_down();
+                } else if response.status.is_success() {
+                    self.rate_limiter.recover();
+                }
+            }
+
+            let (retryable, err) = match response {
+                Ok(response) if response.status == reqwest::StatusCode::NOT_MODIFIED => {
+                    if let Some(cached) = etag_cache.and_then(|c| c.get(url)) {
+                        tracing::trace!(url, "Not modified, serving cached response");
+                        return Ok(cached);
+                    }
+
+                    (
+                        false,
+                        SendError::Status {
+                            status: response.status,
+                            url: url.to_string(),
+                            body: String::new(),
+                        },
+                    )
+                }
+                Ok(response) if response.status.is_success() => {
+                    let response = RawResponse {
+                        status: response.status,
+                        headers: response.headers,
+                        body: response.body,
+                    };
+
+                    if let Some(cache) = etag_cache {
+                        cache.insert(url, &response);
+                    }
+
+                    return Ok(response);
+                }
+                Ok(response)
+                    if response.status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                        && rate_limited_retries < self.retry_policy.max_rate_limited_retries =>
+                {
+                    let delay = parse_retry_after(&response.headers)
+                        .unwrap_or_else(|| self.retry_policy.delay(rate_limited_retries));
+
+                    tracing::info!(
+                        url,
+                        delay_ms = delay.as_millis() as u64,
+                        "Rate limited by the API, waiting before retrying"
+                    );
+
+                    if self.retry_policy.rate_limit_feedback {
+                        self.rate_limiter.drain_for(delay);
+                    }
+                    runtime::sleep(delay).await;
+
+                    rate_limited_retries += 1;
+                    continue;
+                }
+                Ok(response) => {
+                    let status = response.status;
+                    let body = String::from_utf8_lossy(&response.body).into_owned();
+
+                    (
+                        self.retry_policy.should_retry_status(status),
+                        SendError::Status {
+                            status,
+                            url: url.to_string(),
+                            body,
+                        },
+                    )
+                }
+                Err(e) => (self.retry_policy.should_retry_error(&e), SendError::Http(e)),
+            };
+
+            if !retryable || attempt >= self.retry_policy.max_attempts {
+                return Err(err);
+            }
+            if !self.spend_retry() {
+                tracing::warn!(url, attempt, "Retry budget exhausted, not retrying");
+                return Err(err);
+            }
+
+            let delay = self.retry_policy.delay(attempt - 1);
+            tracing::debug!(
+                url,
+                attempt,
+                delay_ms = delay.as_millis() as u64,
+                "Retrying failed request"
+            );
+            runtime::sleep(delay).await;
+
+            attempt += 1;
+        }
     }
 
     /// Performs a standard GET request without pagination.
@@ -115,47 +989,143 @@ impl Client {
     /// # Errors
     ///
     /// Returns `GetError` variants for network issues or non-successful API responses.
+    /// Transient failures are only reported once the retry policy is exhausted.
     pub async fn get<Response>(&self, url: &str) -> Result<Response, GetError>
     where
         Response: DeserializeOwned,
     {
-        self.rate_limiter.acquire(1).await;
-
-        let response = self.inner.get(url).send().await?; // Propagates reqwest::Error via #[from]
-
-        let status = response.status();
+        let url = localize(url, self.language);
+        let response = self.send(&url, None).await?;
 
-        if !status.is_success() {
-            // TODO: Parse the error message if possible
-            let body = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error body: {}", e));
-            return Err(GetError::RequestFailedWithBody {
-                status,
-                body,
-                url: url.to_string(),
-            });
-        }
-
-        Ok(response.json().await?)
+        Ok(self.parse(&url, &response.body)?)
     }
 
-    /// Performs a GET request to a paginated endpoint.
+    /// Performs a GET request like [`Client::get`], but returns the response as is instead of
+    /// deserializing it, for endpoints this crate doesn't model. The request is still
+    /// rate limited, retried, coalesced and authenticated like any other.
     ///
-    /// # Type Parameters
+    /// # Errors
     ///
-    /// * `Response`: The type to deserialize the JSON response *data* into (typically a `Vec<T>`).
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    pub async fn get_raw(&self, url: &str) -> Result<RawResponse, GetError> {
+        Ok(self.send(&localize(url, self.language), None).await?)
+    }
+
+    /// Performs a standard GET request like [`Client::get`], but authenticated with `token`
+    /// instead of the client's API key. Lets one client, and its rate limiter, serve
+    /// requests on behalf of many users.
     ///
-    /// # Arguments
+    /// # Errors
     ///
-    /// * `base_url`: The base URL for the paginated endpoint (without query parameters).
+    /// Returns `GetError::InvalidToken` if `token` is invalid for a header, and otherwise
+    /// the same errors as [`Client::get`].
+    pub async fn get_with_token<Response>(
+        &self,
+        url: &str,
+        token: &str,
+    ) -> Result<Response, GetError>
+    where
+        Response: DeserializeOwned,
+    {
+        let mut authorization =
+            HeaderValue::from_str(&format!("Bearer {}", token)).map_err(GetError::InvalidToken)?;
+        authorization.set_sensitive(true);
+
+        let url = localize(url, self.language);
+        let response = self.send(&url, Some(&authorization)).await?;
+
+        Ok(self.parse(&url, &response.body)?)
+    }
+
+    /// Performs a standard GET request like [`Client::get`], but serves the response from
+    /// the disk cache if it was stored during the current game build. Meant for static
+    /// endpoints (items, recipes, skins, ...) that only change with game updates. The
+    /// current build id is requested from /v2/build, and checked again every few minutes.
+    /// Behaves like [`Client::get`] if no disk cache is configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    /// Failing to read or write the cache is not an error; the response is fetched instead.
+    pub async fn get_cached<Response>(&self, url: &str) -> Result<Response, GetError>
+    where
+        Response: DeserializeOwned,
+    {
+        let Some(cache) = &self.disk_cache else {
+            return self.get(url).await;
+        };
+
+        let build_id = self.current_build_id().await?;
+        let url = localize(url, self.language);
+
+        if let Some(body) = cache.read(build_id, &url) {
+            match self.parse(&url, &body) {
+                Ok(response) => return Ok(response),
+                Err(e) => tracing::warn!(%url, error = %e, "Ignoring invalid disk cache entry"),
+            }
+        }
+
+        let response = self.send(&url, None).await?;
+        let parsed = self.parse(&url, &response.body)?;
+
+        if let Err(e) = cache.write(build_id, &url, &response.body) {
+            tracing::warn!(%url, error = %e, "Failed to write disk cache entry");
+        }
+
+        Ok(parsed)
+    }
+
+    /// The id of the current game build, requested again once the last known one is older
+    /// than `BUILD_ID_MAX_AGE`.
+    async fn current_build_id(&self) -> Result<u32, GetError> {
+        // Held while requesting, so concurrent callers wait for one request
+        let mut build_id = self.build_id.lock().await;
+        let fresh = build_id.filter(|(_, checked)| checked.elapsed() < BUILD_ID_MAX_AGE);
+        if let Some((id, _)) = fresh {
+            return Ok(id);
+        }
+
+        let id = crate::api::build::get_build_id(self).await?;
+        *build_id = Some((id, Instant::now()));
+        Ok(id)
+    }
+
+    /// Performs a standard GET request like [`Client::get`], but localized in `language`
+    /// instead of the client's language.
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    pub async fn get_localized<Response>(
+        &self,
+        url: &str,
+        language: Language,
+    ) -> Result<Response, GetError>
+    where
+        Response: DeserializeOwned,
+    {
+        let url = localize(url, Some(language));
+        let response = self.send(&url, None).await?;
+
+        Ok(self.parse(&url, &response.body)?)
+    }
+
+    /// Performs a GET request to a paginated endpoint.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Response`: The type to deserialize the JSON response *data* into (typically a `Vec<T>`).
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url`: The base URL for the paginated endpoint (without query parameters).
     /// * `params`: The pagination parameters (`page`, `page_size`) for the request.
     ///
     /// # Errors
     ///
     /// Returns `PaginatedGetError` variants for network issues, non-successful API responses,
     /// missing or invalid pagination headers, or JSON deserialization failures.
+    /// Transient failures are only reported once the retry policy is exhausted.
     pub async fn get_paginated<Response>(
         &self,
         base_url: &str,
@@ -164,8 +1134,6 @@ impl Client {
     where
         Response: DeserializeOwned,
     {
-        self.rate_limiter.acquire(1).await;
-
         let paginated_url = if base_url.contains('?') {
             format!("{}&{}", base_url, params.to_query_string())
         } else {
@@ -173,27 +1141,9 @@ impl Client {
         };
 
         let response = self
-            .inner
-            .get(&paginated_url)
-            .send()
-            .await
-            .map_err(PaginatedGetError::Http)?; // Map reqwest::Error explicitly
-
-        let status = response.status();
-        let headers = response.headers().clone(); // Clone headers for potential error reporting
-
-        if !status.is_success() {
-            let body = response
-                .text()
-                .await
-                .unwrap_or_else(|e| format!("Failed to read error body: {}", e));
-
-            return Err(PaginatedGetError::RequestFailedWithBody {
-                status,
-                body,
-                url: paginated_url,
-            });
-        }
+            .send(&localize(&paginated_url, self.language), None)
+            .await?;
+        let headers = &response.headers;
 
         // Helper function to parse required headers
         fn parse_required_header<T: FromStr>(
@@ -210,178 +1160,1578 @@ impl Client {
                         header_name: header_name.to_string(),
                     })?;
 
-            let s = header_value.to_str().map_err(|e| {
-                PaginatedGetError::HeaderParseError {
-                    header_name: header_name.to_string(),
-                    source: Box::new(e), // Box the original error
-                }
-            })?;
+            let s = header_value.to_str().map_err(|e| {
+                PaginatedGetError::HeaderParseError {
+                    header_name: header_name.to_string(),
+                    source: Box::new(e), // Box the original error
+                }
+            })?;
+
+            s.parse::<T>()
+                .map_err(|e| PaginatedGetError::HeaderParseError {
+                    header_name: header_name.to_string(),
+                    source: Box::new(e), // Box the original error
+                })
+        }
+
+        // Extract pagination metadata - return specific errors if headers are missing/invalid
+        let metadata = PaginationMetadata {
+            page: params.page,
+            page_size: parse_required_header(headers, "X-Page-Size")?,
+            page_total: parse_required_header(headers, "X-Page-Total")?,
+            result_count: parse_required_header(headers, "X-Result-Count")?,
+            result_total: parse_required_header(headers, "X-Result-Total")?,
+            links: PageLinks::from_headers(headers, &paginated_url),
+        };
+
+        // Deserialize the JSON body *after* successfully processing headers
+        let data = self
+            .parse(&paginated_url, &response.body)
+            .map_err(PaginatedGetError::DeserializationError)?; // Map deserialization error
+
+        Ok(Paginated { data, metadata })
+    }
+
+    /// Helper method to fetch all pages for a given paginated endpoint.
+    ///
+    /// This method repeatedly calls `get_paginated`, following [`Paginated::next_request`],
+    /// until all pages are fetched. It aggregates the data from each page. Note that this
+    /// can result in many API calls.
+    ///
+    /// # Type Parameters
+    ///
+    /// * `Item`: The type of a single item within the paginated response data (e.g., `Transaction`).
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url`: The base URL for the paginated endpoint.
+    /// * `initial_page_size`: The page size to use for requests.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaginatedGetError` if any of the underlying page requests fail.
+    pub async fn get_all_pages<Item>(
+        &self,
+        base_url: &str,
+        params: PaginationParams,
+    ) -> Result<Vec<Item>, PaginatedGetError>
+    where
+        Vec<Item>: DeserializeOwned, // Ensure the target Vec<Item> can be deserialized
+    {
+        let mut all_items = Vec::new();
+        let mut current_params = Some(params);
+
+        while let Some(params) = current_params {
+            tracing::trace!(
+                "Fetching page {} from {} with params: {:?}",
+                params.page,
+                base_url,
+                params
+            );
+
+            let response: Paginated<Vec<Item>> = self.get_paginated(base_url, params).await?;
+
+            current_params = response.next_request();
+            all_items.extend(response.data);
+        }
+
+        Ok(all_items)
+    }
+
+    /// Fetches every entry of a bulk-expanded endpoint in a single request, using
+    /// `?ids=all`. Only endpoints with small datasets support this; others (e.g. items or
+    /// trading post prices) answer with an error and need [`Client::get_all_pages`].
+    ///
+    /// # Errors
+    ///
+    /// Returns `GetError` variants for network issues or non-successful API responses.
+    pub async fn get_all_by_ids_all<Item>(&self, base_url: &str) -> Result<Vec<Item>, GetError>
+    where
+        Vec<Item>: DeserializeOwned,
+    {
+        let separator = if base_url.contains('?') { '&' } else { '?' };
+        self.get(&format!("{}{}ids=all", base_url, separator)).await
+    }
+
+    /// Fetches all pages like [`Client::get_all_pages`], but gives up once `deadline` has
+    /// passed since the call, so a hung page can't block forever.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PartialResultError` with the items fetched so far if a page request fails
+    /// or the deadline is exceeded (`PaginatedGetError::DeadlineExceeded`).
+    pub async fn get_all_pages_within<Item>(
+        &self,
+        base_url: &str,
+        params: PaginationParams,
+        deadline: Duration,
+    ) -> Result<Vec<Item>, PartialResultError<Item>>
+    where
+        Vec<Item>: DeserializeOwned,
+    {
+        let started = Instant::now();
+        let mut items = Vec::new();
+        let mut current_params = Some(params);
+
+        while let Some(params) = current_params {
+            let page = match runtime::timeout(
+                deadline.saturating_sub(started.elapsed()),
+                self.get_paginated::<Vec<Item>>(base_url, params),
+            )
+            .await
+            {
+                Ok(Ok(page)) => page,
+                Ok(Err(error)) => return Err(PartialResultError { items, error }),
+                Err(_) => {
+                    return Err(PartialResultError {
+                        items,
+                        error: PaginatedGetError::DeadlineExceeded(deadline),
+                    })
+                }
+            };
+
+            current_params = page.next_request();
+            items.extend(page.data);
+        }
+
+        Ok(items)
+    }
+
+    /// Fetches all pages like [`Client::get_all_pages`], but stops as soon as `cancel` is
+    /// cancelled, so long crawls can be shut down cleanly. A page request in flight when
+    /// the token is cancelled is dropped.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `PartialResultError` with the items fetched so far if a page request fails
+    /// or the fetch is cancelled (`PaginatedGetError::Cancelled`).
+    pub async fn get_all_pages_cancellable<Item>(
+        &self,
+        base_url: &str,
+        params: PaginationParams,
+        cancel: &CancellationToken,
+    ) -> Result<Vec<Item>, PartialResultError<Item>>
+    where
+        Vec<Item>: DeserializeOwned,
+    {
+        let mut items = Vec::new();
+        let mut current_params = Some(params);
+
+        while let Some(params) = current_params {
+            let page = tokio::select! {
+                biased;
+                _ = cancel.cancelled() => Err(PaginatedGetError::Cancelled),
+                page = self.get_paginated::<Vec<Item>>(base_url, params) => page,
+            };
+            let page = match page {
+                Ok(page) => page,
+                Err(error) => return Err(PartialResultError { items, error }),
+            };
+
+            current_params = page.next_request();
+            items.extend(page.data);
+        }
+
+        Ok(items)
+    }
+
+    /// Creates a cursor pulling the pages of a paginated endpoint on demand, starting at
+    /// `params`. Unlike [`Client::get_all_pages`], callers decide when to fetch the next
+    /// page, can report progress from the page metadata, and can stop at any point.
+    pub fn page_cursor<Item>(
+        &self,
+        base_url: &str,
+        params: PaginationParams,
+    ) -> PageCursor<'_, Item> {
+        PageCursor {
+            client: self,
+            base_url: base_url.to_string(),
+            next_params: Some(params),
+            metadata: None,
+            pages_fetched: 0,
+            _item: std::marker::PhantomData,
+        }
+    }
+
+    /// Streams the items of every page of a paginated endpoint, starting at `params`.
+    ///
+    /// Unlike [`Client::get_all_pages`], items are yielded as soon as their page arrives and
+    /// only one page is held in memory at a time, so very large endpoints (e.g. every
+    /// trading post listing) can be processed incrementally. Pages are only requested as the
+    /// stream is polled.
+    ///
+    /// # Errors
+    ///
+    /// Yields a `PaginatedGetError` and ends if a page request fails.
+    pub fn stream_pages<'a, Item>(
+        &'a self,
+        base_url: &str,
+        params: PaginationParams,
+    ) -> impl Stream<Item = Result<Item, PaginatedGetError>> + use<'a, Item>
+    where
+        Item: 'a,
+        Vec<Item>: DeserializeOwned,
+    {
+        let base_url = base_url.to_string();
+
+        stream::try_unfold(Some(params), move |next_params| {
+            let base_url = base_url.clone();
+
+            async move {
+                let Some(params) = next_params else {
+                    return Ok::<_, PaginatedGetError>(None);
+                };
+
+                tracing::trace!(
+                    "Streaming page {} from {} with params: {:?}",
+                    params.page,
+                    base_url,
+                    params
+                );
+
+                let page: Paginated<Vec<Item>> = self.get_paginated(&base_url, params).await?;
+                let next_params = page.next_request();
+                let items = stream::iter(page.data.into_iter().map(Ok::<_, PaginatedGetError>));
+
+                Ok(Some((items, next_params)))
+            }
+        })
+        .try_flatten()
+    }
+}
+
+/// Builder for [`Client`], exposing the underlying HTTP connection settings.
+#[derive(Debug)]
+pub struct ClientBuilder {
+    token: Option<Cow<'static, str>>,
+    user_agent: Option<Cow<'static, str>>,
+    base_url: Cow<'static, str>,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    #[cfg(not(target_arch = "wasm32"))]
+    proxy: Option<reqwest::Proxy>,
+    pool_max_idle_per_host: Option<usize>,
+    pool_idle_timeout: Option<Duration>,
+    transport: Option<Box<dyn Transport>>,
+    record_to: Option<PathBuf>,
+    middleware: Vec<Box<dyn Middleware>>,
+    auth: Option<Box<dyn AuthProvider>>,
+    rate_limiter: Option<Arc<rate_limiter::RateLimiter>>,
+    priority: rate_limiter::Priority,
+    max_concurrent_requests: Option<usize>,
+    request_coalescing: bool,
+    disk_cache: Option<DiskCache>,
+    strict_schema: bool,
+    retry_policy: RetryPolicy,
+    conditional_requests: bool,
+    language: Option<Language>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            token: None,
+            user_agent: None,
+            base_url: Cow::Borrowed(DEFAULT_BASE_URL),
+            timeout: None,
+            connect_timeout: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            proxy: None,
+            pool_max_idle_per_host: None,
+            pool_idle_timeout: None,
+            transport: None,
+            record_to: None,
+            middleware: Vec::new(),
+            auth: None,
+            rate_limiter: None,
+            priority: rate_limiter::Priority::Normal,
+            max_concurrent_requests: None,
+            request_coalescing: true,
+            disk_cache: None,
+            strict_schema: false,
+            retry_policy: RetryPolicy::default(),
+            conditional_requests: false,
+            language: None,
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// Sets the API token (bearer token) sent with every request.
+    pub fn token(mut self, token: impl Into<Cow<'static, str>>) -> Self {
+        self.token = Some(token.into());
+        self
+    }
+
+    /// Identifies the tool making requests, e.g. `my-tool/1.2 (contact@example.com)`.
+    /// [`CRATE_USER_AGENT`] is appended to it in the `User-Agent` header, which is just
+    /// [`CRATE_USER_AGENT`] by default.
+    pub fn user_agent(mut self, user_agent: impl Into<Cow<'static, str>>) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Sets the host endpoint urls are built on, e.g. a local mock server or a caching
+    /// proxy. Defaults to [`DEFAULT_BASE_URL`].
+    pub fn base_url(mut self, base_url: impl Into<Cow<'static, str>>) -> Self {
+        let base_url = base_url.into();
+        self.base_url = match base_url.strip_suffix('/') {
+            Some(trimmed) => Cow::Owned(trimmed.to_string()),
+            None => base_url,
+        };
+        self
+    }
+
+    /// Sets a timeout for each request, from connecting until the body has been read.
+    /// Applies to every transport, and timed out requests are retried according to the
+    /// retry policy. No timeout by default.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sets a timeout for establishing connections. No timeout by default.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Routes every request through the given proxy. By default, proxies are picked up
+    /// from the environment (`HTTPS_PROXY`, ...). Not available on wasm.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn proxy(mut self, proxy: reqwest::Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the maximum number of idle connections kept open to the API.
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// Sets how long idle connections are kept open. Defaults to 90 seconds.
+    pub fn pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.pool_idle_timeout = Some(timeout);
+        self
+    }
+
+    /// Sends requests through `transport` instead of reqwest, e.g. to serve canned
+    /// responses in tests. The connection settings of this builder are then ignored.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Box::new(transport));
+        self
+    }
+
+    /// Stores every response in `dir`, to be replayed later with [`ClientBuilder::replay_from`].
+    pub fn record_to(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.record_to = Some(dir.into());
+        self
+    }
+
+    /// Serves the responses recorded to `dir` instead of sending requests, see
+    /// [`ReplayTransport`].
+    pub fn replay_from(self, dir: impl Into<PathBuf>) -> Self {
+        self.transport(ReplayTransport::new(dir))
+    }
+
+    /// Uses `rate_limiter` for requests, e.g. to share one limiter between several clients
+    /// with different tokens, since the API rate-limits per IP rather than per key.
+    /// Defaults to a new limiter allowing bursts of 300 requests, refilling 5 per second.
+    pub fn rate_limiter(mut self, rate_limiter: Arc<rate_limiter::RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Sets the priority this client's requests wait for the rate limiter with. Combined
+    /// with a shared rate limiter, e.g. a client for interactive lookups can jump ahead of
+    /// one running a background crawl. Defaults to [`rate_limiter::Priority::Normal`].
+    pub fn priority(mut self, priority: rate_limiter::Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Limits how many requests the client sends at once, regardless of how many calls are
+    /// made concurrently. Unlimited by default; requests are still rate-limited.
+    pub fn max_concurrent_requests(mut self, max: usize) -> Self {
+        self.max_concurrent_requests = Some(max.max(1));
+        self
+    }
+
+    /// Coalesces concurrent requests to the same url into a single HTTP call whose response
+    /// is shared by all of them, saving rate limit tokens when e.g. a dashboard re-renders
+    /// while its previous requests are still running. Enabled by default.
+    pub fn request_coalescing(mut self, enabled: bool) -> Self {
+        self.request_coalescing = enabled;
+        self
+    }
+
+    /// Stores the responses of [`Client::get_cached`] in `cache`, so they survive restarts.
+    pub fn disk_cache(mut self, cache: DiskCache) -> Self {
+        self.disk_cache = Some(cache);
+        self
+    }
+
+    /// Enables strict deserialization: responses are checked against the models they are
+    /// deserialized into, and fields one of them lacks are logged and collected as
+    /// [`SchemaWarning`]s, each reported once. Meant for crawls detecting changes to the
+    /// API's schema, as it makes deserialization slower.
+    pub fn strict_schema(mut self, enabled: bool) -> Self {
+        self.strict_schema = enabled;
+        self
+    }
+
+    /// Sets the policy used to retry transient failures (5xx responses, network errors and
+    /// timeouts). Defaults to [`RetryPolicy::default`].
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Enables conditional requests: responses carrying an `ETag` are kept in memory and
+    /// subsequent requests to the same url send `If-None-Match`, so unchanged data is
+    /// answered with 304 Not Modified and served from memory. Worthwhile for repeated crawls
+    /// of static endpoints (items, recipes, ...), but note cached bodies are never evicted.
+    /// Disabled by default.
+    pub fn conditional_requests(mut self, enabled: bool) -> Self {
+        self.conditional_requests = enabled;
+        self
+    }
+
+    /// Sets the language names and descriptions are returned in, by appending `lang=` to
+    /// requests to localized endpoints. Defaults to the API's default (English).
+    pub fn language(mut self, language: Language) -> Self {
+        self.language = Some(language);
+        self
+    }
+
+    /// Authenticates requests with the header supplied by `provider` instead of the token,
+    /// e.g. [`SubtokenRotation`].
+    pub fn auth_provider(mut self, provider: impl AuthProvider + 'static) -> Self {
+        self.auth = Some(Box::new(provider));
+        self
+    }
+
+    /// Adds a middleware layer. Layers run on requests in the order they were added, and
+    /// on responses in reverse order.
+    pub fn middleware(mut self, layer: impl Middleware + 'static) -> Self {
+        self.middleware.push(Box::new(layer));
+        self
+    }
+
+    /// Builds the client.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP client cannot be built, if the token or user agent are
+    /// invalid for a header, or if the base url isn't a valid url with a path.
+    pub fn build(self) -> Result<Client, NewClientError> {
+        let base_url = reqwest::Url::parse(&self.base_url)
+            .ok()
+            .filter(|url| !url.cannot_be_a_base())
+            .ok_or_else(|| NewClientError::InvalidBaseUrl(self.base_url.to_string()))?;
+
+        let mut headers = HeaderMap::new();
+        let user_agent = match &self.user_agent {
+            Some(user_agent) => {
+                HeaderValue::from_str(&format!("{} {}", user_agent, CRATE_USER_AGENT))?
+            }
+            None => HeaderValue::from_static(CRATE_USER_AGENT),
+        };
+        headers.insert(USER_AGENT, user_agent);
+
+        if let Some(token_ref) = &self.token {
+            let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", token_ref))?;
+            auth_value.set_sensitive(true); // Mark the token as sensitive
+            headers.insert(AUTHORIZATION, auth_value);
+        }
+
+        let mut transport = match self.transport {
+            Some(transport) => transport,
+            None => Box::new(ReqwestTransport::new(self.build_reqwest()?)),
+        };
+        if let Some(dir) = self.record_to {
+            transport = Box::new(RecordingTransport::boxed(transport, dir)?);
+        }
+
+        Ok(Client {
+            transport,
+            middleware: self.middleware,
+            auth: self.auth,
+            metrics: metrics::Recorder::default(),
+            headers,
+            token: self.token,
+            base_url,
+            rate_limiter: self
+                .rate_limiter
+                .unwrap_or_else(|| Arc::new(rate_limiter::RateLimiter::new(300, 5.0))),
+            priority: self.priority,
+            retry_policy: self.retry_policy,
+            etag_cache: self.conditional_requests.then(ETagCache::default),
+            language: self.language,
+            token_info: tokio::sync::OnceCell::new(),
+            in_flight: self.request_coalescing.then(Default::default),
+            disk_cache: self.disk_cache,
+            build_id: Default::default(),
+            request_timeout: self.timeout,
+            concurrency_limit: self
+                .max_concurrent_requests
+                .map(tokio::sync::Semaphore::new),
+            recent_retries: Mutex::new(VecDeque::new()),
+            schema: self.strict_schema.then(schema::Recorder::default),
+        })
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn build_reqwest(&self) -> Result<reqwest::Client, reqwest::Error> {
+        // Connections are managed by the browser
+        let _ = (
+            self.connect_timeout,
+            self.pool_max_idle_per_host,
+            self.pool_idle_timeout,
+        );
+        reqwest::ClientBuilder::new().build()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn build_reqwest(&self) -> Result<reqwest::Client, reqwest::Error> {
+        let mut builder = reqwest::ClientBuilder::new();
+
+        if let Some(timeout) = self.connect_timeout {
+            builder = builder.connect_timeout(timeout);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = self.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        // Prefer rustls when both backends are enabled
+        #[cfg(feature = "rustls")]
+        {
+            builder = builder.use_rustls_tls();
+        }
+
+        builder.build()
+    }
+}
+
+/// Parameters for paginated API requests.
+#[derive(Debug, Clone, Copy)]
+pub struct PaginationParams {
+    /// The page number (0-indexed).
+    pub page: usize,
+    /// Number of items per page (max typically 200 for GW2 API).
+    pub page_size: usize,
+}
+
+impl Default for PaginationParams {
+    /// Defaults to the first page with a size of 200.
+    fn default() -> Self {
+        Self {
+            page: 0,
+            page_size: DEFAULT_PAGE_SIZE, // Common GW2 API max page size
+        }
+    }
+}
+
+impl PaginationParams {
+    /// Creates new pagination parameters.
+    pub fn new(page: usize, page_size: usize) -> Self {
+        Self { page, page_size }
+    }
+
+    /// Creates parameters for the first page with a specific size.
+    pub fn first(page_size: usize) -> Self {
+        Self { page: 0, page_size }
+    }
+
+    /// Reads the `page` and `page_size` query parameters of `url`.
+    fn from_url(url: &str) -> Option<Self> {
+        let url = reqwest::Url::parse(url).ok()?;
+        let param = |name: &str| {
+            url.query_pairs()
+                .find(|(key, _)| key == name)
+                .and_then(|(_, value)| value.parse().ok())
+        };
+
+        Some(Self::new(param("page")?, param("page_size")?))
+    }
+
+    /// Formats the parameters as a query string fragment (without leading '?').
+    pub fn to_query_string(&self) -> String {
+        format!("page={}&page_size={}", self.page, self.page_size)
+    }
+
+    /// Gets the parameters for the next page.
+    pub fn next(&self) -> PaginationParams {
+        PaginationParams {
+            page: self.page + 1,
+            page_size: self.page_size,
+        }
+    }
+}
+
+/// Metadata extracted from paginated API response headers.
+#[derive(Debug, Clone)]
+pub struct PaginationMetadata {
+    /// The page that was requested (0-indexed).
+    pub page: usize,
+    /// Number of entries per page (obtained from response).
+    pub page_size: usize,
+    /// Total number of pages available (obtained from response).
+    pub page_total: usize,
+    /// Number of results in the current response (obtained from response).
+    pub result_count: usize,
+    /// Total number of results across all pages (obtained from response).
+    pub result_total: usize,
+    /// Links to neighbouring pages, from the `Link` header.
+    pub links: PageLinks,
+}
+
+/// Absolute urls of the pages linked from a page by its `Link` header, e.g.
+/// `</v2/commerce/listings?page=1&page_size=200>; rel=next`. Missing links are `None`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PageLinks {
+    pub first: Option<String>,
+    pub previous: Option<String>,
+    pub next: Option<String>,
+    pub last: Option<String>,
+}
+
+impl PageLinks {
+    /// Parses the `Link` header of the response to `url`, resolving relative links.
+    fn from_headers(headers: &HeaderMap, url: &str) -> Self {
+        let mut links = Self::default();
+        let base = reqwest::Url::parse(url).ok();
+
+        for value in headers.get_all(LINK) {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+
+            for link in value.split(',') {
+                let mut parts = link.split(';');
+                let Some(target) = parts
+                    .next()
+                    .map(str::trim)
+                    .and_then(|target| target.strip_prefix('<')?.strip_suffix('>'))
+                else {
+                    continue;
+                };
+                let target = match &base {
+                    Some(base) => base.join(target).map_or(target.to_string(), String::from),
+                    None => target.to_string(),
+                };
+
+                for param in parts {
+                    let Some((name, rel)) = param.split_once('=') else {
+                        continue;
+                    };
+                    if name.trim() != "rel" {
+                        continue;
+                    }
+                    let slot = match rel.trim().trim_matches('"') {
+                        "first" => &mut links.first,
+                        "previous" | "prev" => &mut links.previous,
+                        "next" => &mut links.next,
+                        "last" => &mut links.last,
+                        _ => continue,
+                    };
+                    *slot = Some(target.clone());
+                }
+            }
+        }
+
+        links
+    }
+
+    fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Pulls the pages of a paginated endpoint one at a time, see [`Client::page_cursor`].
+#[derive(Debug)]
+pub struct PageCursor<'a, Item> {
+    client: &'a Client,
+    base_url: String,
+    next_params: Option<PaginationParams>,
+    metadata: Option<PaginationMetadata>,
+    pages_fetched: usize,
+    _item: std::marker::PhantomData<fn() -> Item>,
+}
+
+impl<Item> PageCursor<'_, Item>
+where
+    Vec<Item>: DeserializeOwned,
+{
+    /// Fetches the next page, or returns `None` once every page was fetched.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PaginatedGetError` if the page request fails. The same page is requested
+    /// again on the next call.
+    pub async fn next_page(&mut self) -> Result<Option<Vec<Item>>, PaginatedGetError> {
+        let Some(params) = self.next_params else {
+            return Ok(None);
+        };
+
+        let page: Paginated<Vec<Item>> = self.client.get_paginated(&self.base_url, params).await?;
+
+        self.next_params = page.next_request();
+        self.metadata = Some(page.metadata);
+        self.pages_fetched += 1;
+
+        Ok(Some(page.data))
+    }
+
+    /// Metadata of the last fetched page, `None` before the first page was fetched.
+    pub fn metadata(&self) -> Option<&PaginationMetadata> {
+        self.metadata.as_ref()
+    }
+
+    /// Number of pages fetched so far.
+    pub fn pages_fetched(&self) -> usize {
+        self.pages_fetched
+    }
+
+    /// Whether every page was fetched.
+    pub fn is_done(&self) -> bool {
+        self.next_params.is_none()
+    }
+}
+
+/// Represents a paginated response, containing both the data and pagination metadata.
+#[derive(Debug, Clone)]
+pub struct Paginated<T> {
+    /// The actual data returned by the API for the current page.
+    pub data: T,
+    /// Pagination metadata extracted from response headers.
+    pub metadata: PaginationMetadata,
+}
+
+impl<T> Paginated<T> {
+    /// The parameters of the next page, or `None` on the last page. Follows the `Link`
+    /// header, and falls back to counting pages if the API sent none or its next link
+    /// can't be parsed.
+    pub fn next_request(&self) -> Option<PaginationParams> {
+        let metadata = &self.metadata;
+        let counted = || {
+            (metadata.page + 1 < metadata.page_total)
+                .then(|| PaginationParams::new(metadata.page + 1, metadata.page_size))
+        };
+        match &metadata.links.next {
+            Some(next) => PaginationParams::from_url(next).or_else(|| {
+                tracing::warn!(%next, "Unparsable next page link, counting pages instead");
+                counted()
+            }),
+            None if metadata.links.is_empty() => counted(),
+            None => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_uses_configured_base_url() {
+        let client = Client::builder().build().unwrap();
+        assert_eq!(
+            client.url("/v2/items"),
+            "https://api.guildwars2.com/v2/items"
+        );
+
+        let client = Client::builder()
+            .base_url("http://localhost:8080/")
+            .build()
+            .unwrap();
+        assert_eq!(client.url("/v2/items"), "http://localhost:8080/v2/items");
+
+        let client = Client::builder()
+            .base_url("http://localhost:8080/proxy")
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url(), "http://localhost:8080/proxy");
+
+        for base_url in ["not a url", "mailto:api@example.com"] {
+            assert!(matches!(
+                Client::builder().base_url(base_url).build(),
+                Err(NewClientError::InvalidBaseUrl(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn user_agent_appends_crate_version() {
+        let client = Client::builder().build().unwrap();
+        assert_eq!(client.headers[USER_AGENT], CRATE_USER_AGENT);
+
+        let client = Client::builder()
+            .user_agent("my-tool/1.2 (contact@example.com)")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.headers[USER_AGENT],
+            format!(
+                "my-tool/1.2 (contact@example.com) gw2gd/{}",
+                env!("CARGO_PKG_VERSION")
+            )
+        );
+    }
+
+    #[test]
+    fn next_request_follows_link_header() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            LINK,
+            HeaderValue::from_static(
+                "</v2/items?page=0&page_size=50>; rel=previous, \
+                 </v2/items?page=2&page_size=50>; rel=next, \
+                 </v2/items?page=0&page_size=50>; rel=first, \
+                 </v2/items?page=3&page_size=50>; rel=last",
+            ),
+        );
+        let links = PageLinks::from_headers(
+            &headers,
+            "https://api.guildwars2.com/v2/items?page=1&page_size=50",
+        );
+        assert_eq!(
+            links.next.as_deref(),
+            Some("https://api.guildwars2.com/v2/items?page=2&page_size=50")
+        );
+        assert_eq!(
+            links.last.as_deref(),
+            Some("https://api.guildwars2.com/v2/items?page=3&page_size=50")
+        );
+
+        let mut page = Paginated {
+            data: (),
+            metadata: PaginationMetadata {
+                page: 1,
+                page_size: 50,
+                page_total: 4,
+                result_count: 50,
+                result_total: 200,
+                links,
+            },
+        };
+        let next = page.next_request().unwrap();
+        assert_eq!((next.page, next.page_size), (2, 50));
+
+        page.metadata.links.next = Some("https://api.guildwars2.com/v2/items".to_string());
+        let next = page.next_request().unwrap();
+        assert_eq!((next.page, next.page_size), (2, 50));
+
+        page.metadata.links.next = None;
+        assert!(page.next_request().is_none());
+
+        page.metadata.links = PageLinks::default();
+        let next = page.next_request().unwrap();
+        assert_eq!((next.page, next.page_size), (2, 50));
+    }
+
+    #[test]
+    fn retry_delay_backs_off_exponentially() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_millis(350),
+            jitter: false,
+            ..Default::default()
+        };
+
+        assert_eq!(policy.delay(0), Duration::from_millis(100));
+        assert_eq!(policy.delay(1), Duration::from_millis(200));
+        assert_eq!(policy.delay(2), Duration::from_millis(350));
+        assert_eq!(policy.delay(40), Duration::from_millis(350));
+    }
+
+    #[test]
+    fn retry_delay_jitter_stays_in_range() {
+        let policy = RetryPolicy {
+            base_delay: Duration::from_millis(100),
+            ..Default::default()
+        };
+
+        for _ in 0..100 {
+            let delay = policy.delay(0);
+            assert!(delay >= Duration::from_millis(50) && delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn parses_retry_after_seconds() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(parse_retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("3"));
+        assert_eq!(parse_retry_after(&headers), Some(Duration::from_secs(3)));
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(parse_retry_after(&headers), None);
+    }
+
+    #[test]
+    fn etag_cache_only_keeps_tagged_responses() {
+        let cache = ETagCache::default();
+        let untagged = RawResponse {
+            status: reqwest::StatusCode::OK,
+            headers: HeaderMap::new(),
+            body: Bytes::from_static(b"[1]"),
+        };
+        cache.insert("https://example.com/a", &untagged);
+        assert!(cache.get("https://example.com/a").is_none());
+
+        let mut headers = HeaderMap::new();
+        headers.insert(ETAG, HeaderValue::from_static("\"abc\""));
+        let tagged = RawResponse {
+            status: reqwest::StatusCode::OK,
+            headers,
+            body: Bytes::from_static(b"[2]"),
+        };
+        cache.insert("https://example.com/b", &tagged);
+        assert_eq!(
+            cache.etag("https://example.com/b"),
+            Some(HeaderValue::from_static("\"abc\""))
+        );
+        assert_eq!(
+            cache.get("https://example.com/b").unwrap().body,
+            tagged.body
+        );
+
+        cache.clear();
+        assert!(cache.etag("https://example.com/b").is_none());
+    }
+
+    #[test]
+    fn localize_appends_lang() {
+        assert_eq!(localize("https://a/v2/items", None), "https://a/v2/items");
+        assert_eq!(
+            localize("https://a/v2/items", Some(Language::German)),
+            "https://a/v2/items?lang=de"
+        );
+        assert_eq!(
+            localize("https://a/v2/items?ids=1,2", Some(Language::French)),
+            "https://a/v2/items?ids=1,2&lang=fr"
+        );
+        assert_eq!(
+            localize("https://a/v2/items?lang=es", Some(Language::French)),
+            "https://a/v2/items?lang=es"
+        );
+    }
+
+    #[test]
+    fn localize_skips_non_localized_endpoints() {
+        for url in [
+            "https://a/v2/commerce/prices?ids=1",
+            "https://a/v2/account/bank",
+            "https://a/v2/tokeninfo",
+            "https://a/v2/build",
+        ] {
+            assert_eq!(localize(url, Some(Language::German)), url);
+        }
+        assert_eq!(
+            localize("https://a/v2/achievements", Some(Language::German)),
+            "https://a/v2/achievements?lang=de"
+        );
+    }
+
+    /// Answers every request with the same page of ids.
+    #[derive(Debug)]
+    struct CannedTransport;
+
+    impl Transport for CannedTransport {
+        fn send(&self, request: transport::Request) -> transport::TransportFuture<'_> {
+            Box::pin(async move {
+                let mut headers = HeaderMap::new();
+                for (name, value) in [
+                    ("X-Page-Size", "2"),
+                    ("X-Page-Total", "1"),
+                    ("X-Result-Count", "2"),
+                    ("X-Result-Total", "2"),
+                ] {
+                    headers.insert(name, HeaderValue::from_static(value));
+                }
+                let auth = request
+                    .headers
+                    .get(AUTHORIZATION)
+                    .unwrap()
+                    .to_str()
+                    .unwrap();
+                headers.insert("X-Auth", HeaderValue::from_str(auth).unwrap());
+
+                Ok(transport::Response {
+                    status: reqwest::StatusCode::OK,
+                    headers,
+                    body: Bytes::from_static(b"[1, 2]"),
+                })
+            })
+        }
+    }
+
+    /// Serves pages whose `X-Page-Total` overstates the pages reachable by `Link` headers.
+    #[derive(Debug)]
+    struct LinkedPages;
+
+    impl Transport for LinkedPages {
+        fn send(&self, request: transport::Request) -> transport::TransportFuture<'_> {
+            Box::pin(async move {
+                let page = if request.url.contains("page=1") { 1 } else { 0 };
+                let mut headers = HeaderMap::new();
+                for (name, value) in [
+                    ("X-Page-Size", "1"),
+                    ("X-Page-Total", "5"),
+                    ("X-Result-Count", "1"),
+                    ("X-Result-Total", "5"),
+                ] {
+                    headers.insert(name, HeaderValue::from_static(value));
+                }
+                let link = if page == 0 {
+                    "</v2/items?page=0&page_size=1>; rel=first, \
+                     </v2/items?page=1&page_size=1>; rel=next"
+                } else {
+                    "</v2/items?page=0&page_size=1>; rel=first"
+                };
+                headers.insert(LINK, HeaderValue::from_static(link));
+
+                Ok(transport::Response {
+                    status: reqwest::StatusCode::OK,
+                    headers,
+                    body: Bytes::from(format!("[{}]", page)),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn get_all_pages_follows_links() {
+        let client = Client::builder().transport(LinkedPages).build().unwrap();
+
+        let ids: Vec<u32> = client
+            .get_all_pages("https://example.com/v2/items", PaginationParams::first(1))
+            .await
+            .unwrap();
+        assert_eq!(ids, vec![0, 1]);
+    }
+
+    #[tokio::test]
+    async fn custom_transport_serves_requests() {
+        let client = Client::builder()
+            .token("secret")
+            .transport(CannedTransport)
+            .build()
+            .unwrap();
+
+        let ids: Vec<u32> = client.get("https://example.com/v2/items").await.unwrap();
+        assert_eq!(ids, vec![1, 2]);
+
+        let raw = client
+            .get_raw("https://example.com/v2/items")
+            .await
+            .unwrap();
+        assert_eq!(raw.status, reqwest::StatusCode::OK);
+        assert_eq!(raw.body, Bytes::from_static(b"[1, 2]"));
+
+        let page: Paginated<Vec<u32>> = client
+            .get_paginated("https://example.com/v2/items", PaginationParams::first(2))
+            .await
+            .unwrap();
+        assert_eq!(page.data, vec![1, 2]);
+        assert_eq!(page.metadata.result_total, 2);
+
+        let mut cursor =
+            client.page_cursor::<u32>("https://example.com/v2/items", PaginationParams::first(2));
+        assert_eq!(cursor.next_page().await.unwrap(), Some(vec![1, 2]));
+        assert_eq!(cursor.metadata().unwrap().page_total, 1);
+        assert!(cursor.is_done());
+        assert_eq!(cursor.next_page().await.unwrap(), None);
+
+        let metrics = client.metrics();
+        assert_eq!(metrics.requests(), 4);
+        assert_eq!(metrics.endpoints["/v2/items"].errors, 0);
+    }
 
-            s.parse::<T>()
-                .map_err(|e| PaginatedGetError::HeaderParseError {
-                    header_name: header_name.to_string(),
-                    source: Box::new(e), // Box the original error
+    #[tokio::test]
+    async fn client_can_be_shared_across_tasks() {
+        let client = std::sync::Arc::new(
+            Client::builder()
+                .token("secret")
+                .transport(CannedTransport)
+                .build()
+                .unwrap(),
+        );
+
+        let tasks: Vec<_> = (0..3)
+            .map(|_| {
+                let client = client.clone();
+                tokio::spawn(async move {
+                    client
+                        .get::<Vec<u32>>("https://example.com/v2/items")
+                        .await
+                        .unwrap()
                 })
+            })
+            .collect();
+        for task in tasks {
+            assert_eq!(task.await.unwrap(), vec![1, 2]);
         }
+    }
 
-        // Extract pagination metadata - return specific errors if headers are missing/invalid
-        let metadata = PaginationMetadata {
-            page_size: parse_required_header(&headers, "X-Page-Size")?,
-            page_total: parse_required_header(&headers, "X-Page-Total")?,
-            result_count: parse_required_header(&headers, "X-Result-Count")?,
-            result_total: parse_required_header(&headers, "X-Result-Total")?,
-        };
+    #[test]
+    fn clients_can_share_a_rate_limiter() {
+        let first = Client::new(None).unwrap();
+        let second = Client::builder()
+            .token("secret")
+            .rate_limiter(first.rate_limiter().clone())
+            .build()
+            .unwrap();
 
-        // Deserialize the JSON body *after* successfully processing headers
-        let data = response
-            .json()
-            .await
-            .map_err(PaginatedGetError::DeserializationError)?; // Map deserialization error
+        assert!(Arc::ptr_eq(first.rate_limiter(), second.rate_limiter()));
+    }
 
-        Ok(Paginated { data, metadata })
+    /// Answers every request with an empty list, after a delay.
+    #[derive(Debug)]
+    struct SlowTransport;
+
+    impl Transport for SlowTransport {
+        fn send(&self, _request: transport::Request) -> transport::TransportFuture<'_> {
+            Box::pin(async {
+                tokio::time::sleep(Duration::from_millis(50)).await;
+                Ok(transport::Response {
+                    status: reqwest::StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: Bytes::from_static(b"[]"),
+                })
+            })
+        }
     }
 
-    /// Helper method to fetch all pages for a given paginated endpoint.
-    ///
-    /// This method repeatedly calls `get_paginated` until all pages are fetched.
-    /// It aggregates the data from each page. Note that this can result in many API calls.
-    ///
-    /// # Type Parameters
-    ///
-    /// * `Item`: The type of a single item within the paginated response data (e.g., `Transaction`).
-    ///
-    /// # Arguments
-    ///
-    /// * `base_url`: The base URL for the paginated endpoint.
-    /// * `initial_page_size`: The page size to use for requests.
-    ///
-    /// # Errors
-    ///
-    /// Returns `PaginatedGetError` if any of the underlying page requests fail.
-    pub async fn get_all_pages<Item>(
-        &self,
-        base_url: &str,
-        params: PaginationParams,
-    ) -> Result<Vec<Item>, PaginatedGetError>
-    where
-        Vec<Item>: DeserializeOwned, // Ensure the target Vec<Item> can be deserialized
-    {
-        let mut all_items = Vec::new();
-        let mut current_params = params;
+    #[tokio::test]
+    async fn concurrent_requests_are_coalesced() {
+        let client = Client::builder().transport(SlowTransport).build().unwrap();
+        let url = "https://example.com/v2/items";
+
+        let (first, second) =
+            tokio::join!(client.get::<Vec<u32>>(url), client.get::<Vec<u32>>(url));
+        first.unwrap();
+        second.unwrap();
+        assert_eq!(client.metrics().requests(), 1);
+
+        let client = Client::builder()
+            .transport(SlowTransport)
+            .request_coalescing(false)
+            .build()
+            .unwrap();
+        let (first, second) =
+            tokio::join!(client.get::<Vec<u32>>(url), client.get::<Vec<u32>>(url));
+        first.unwrap();
+        second.unwrap();
+        assert_eq!(client.metrics().requests(), 2);
+    }
 
-        tracing::trace!(
-            "Fetching first page from {} with params: {:?}",
-            base_url,
-            current_params
+    #[tokio::test]
+    async fn requests_with_different_tokens_are_not_coalesced() {
+        let client = Client::builder().transport(SlowTransport).build().unwrap();
+        let url = "https://example.com/v2/account";
+
+        let (first, second, third) = tokio::join!(
+            client.get_with_token::<Vec<u32>>(url, "first"),
+            client.get_with_token::<Vec<u32>>(url, "second"),
+            client.get_with_token::<Vec<u32>>(url, "second"),
         );
+        first.unwrap();
+        second.unwrap();
+        third.unwrap();
+        assert_eq!(client.metrics().requests(), 2);
+
+        assert!(matches!(
+            client.get_with_token::<Vec<u32>>(url, "bad\ntoken").await,
+            Err(GetError::InvalidToken(_))
+        ));
+    }
 
-        let first_response: Paginated<Vec<Item>> =
-            self.get_paginated(base_url, current_params).await?;
+    #[tokio::test]
+    async fn concurrent_requests_are_capped() {
+        let client = Client::builder()
+            .transport(SlowTransport)
+            .max_concurrent_requests(1)
+            .request_coalescing(false)
+            .build()
+            .unwrap();
+        let url = "https://example.com/v2/items";
+
+        let started = Instant::now();
+        let (first, second) =
+            tokio::join!(client.get::<Vec<u32>>(url), client.get::<Vec<u32>>(url));
+        first.unwrap();
+        second.unwrap();
+        assert!(started.elapsed() >= Duration::from_millis(100));
+    }
 
-        all_items.extend(first_response.data);
+    /// Answers every request with 503 Service Unavailable, as during maintenance.
+    #[derive(Debug)]
+    struct Unavailable;
+
+    impl Transport for Unavailable {
+        fn send(&self, _request: transport::Request) -> transport::TransportFuture<'_> {
+            Box::pin(async {
+                Ok(transport::Response {
+                    status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+                    headers: HeaderMap::new(),
+                    body: Bytes::from_static(br#"{"text": "API not active"}"#),
+                })
+            })
+        }
+    }
 
-        for page in 1..first_response.metadata.page_total {
-            current_params = current_params.next();
+    #[tokio::test]
+    async fn health_check_detects_maintenance() {
+        let client = Client::builder().transport(Unavailable).build().unwrap();
+        assert!(matches!(
+            client.health_check().await,
+            Health::Maintenance(_)
+        ));
 
-            tracing::trace!(
-                "Fetching page {} from {} with params: {:?}",
-                page,
-                base_url,
-                current_params
-            );
+        let client = Client::builder().transport(StaticData).build().unwrap();
+        assert!(client.health_check().await.is_up());
+    }
 
-            let response: Paginated<Vec<Item>> =
-                self.get_paginated(base_url, current_params).await?;
+    #[tokio::test]
+    async fn retries_stop_once_budget_is_spent() {
+        let client = Client::builder()
+            .transport(Unavailable)
+            .retry_policy(RetryPolicy {
+                max_attempts: 5,
+                base_delay: Duration::from_millis(1),
+                jitter: false,
+                budget: Some(RetryBudget {
+                    max_retries: 2,
+                    window: Duration::from_secs(60),
+                }),
+                ..Default::default()
+            })
+            .build()
+            .unwrap();
+        let url = "https://example.com/v2/items";
+
+        assert!(client.get::<Vec<u32>>(url).await.is_err());
+        assert_eq!(client.metrics().requests(), 3);
+
+        assert!(client.get::<Vec<u32>>(url).await.is_err());
+        assert_eq!(client.metrics().requests(), 4);
+    }
 
-            all_items.extend(response.data);
+    /// Serves /v2/build, and a list of ids for everything else.
+    #[derive(Debug)]
+    struct StaticData;
+
+    impl Transport for StaticData {
+        fn send(&self, request: transport::Request) -> transport::TransportFuture<'_> {
+            Box::pin(async move {
+                let body: &'static [u8] = if request.url.ends_with("/v2/build") {
+                    br#"{"id": 115267}"#
+                } else {
+                    b"[1, 2, 3]"
+                };
+
+                Ok(transport::Response {
+                    status: reqwest::StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: Bytes::from_static(body),
+                })
+            })
         }
-
-        Ok(all_items)
     }
-}
 
-/// Parameters for paginated API requests.
-#[derive(Debug, Clone, Copy)]
-pub struct PaginationParams {
-    /// The page number (0-indexed).
-    pub page: usize,
-    /// Number of items per page (max typically 200 for GW2 API).
-    pub page_size: usize,
-}
+    #[tokio::test]
+    async fn disk_cache_survives_clients() {
+        let dir = std::env::temp_dir().join(format!("gw2gd-disk-cache-{}", std::process::id()));
+        let cache = DiskCache::new(&dir).unwrap();
+        let url = "https://example.com/v2/items";
+
+        for expected_requests in [1, 0] {
+            let client = Client::builder()
+                .transport(StaticData)
+                .disk_cache(cache.clone())
+                .build()
+                .unwrap();
+
+            let ids: Vec<u32> = client.get_cached(url).await.unwrap();
+            assert_eq!(ids, [1, 2, 3]);
+
+            let requests = client
+                .metrics()
+                .endpoints
+                .get("/v2/items")
+                .map_or(0, |endpoint| endpoint.requests);
+            assert_eq!(requests, expected_requests);
+        }
 
-impl Default for PaginationParams {
-    /// Defaults to the first page with a size of 200.
-    fn default() -> Self {
-        Self {
-            page: 0,
-            page_size: DEFAULT_PAGE_SIZE, // Common GW2 API max page size
+        // A long-lived client checks the build again once it is old
+        let client = Client::builder()
+            .transport(StaticData)
+            .disk_cache(cache.clone())
+            .build()
+            .unwrap();
+        let build_requests = |client: &Client| client.metrics().endpoints["/v2/build"].requests;
+        let _: Vec<u32> = client.get_cached(url).await.unwrap();
+        let _: Vec<u32> = client.get_cached(url).await.unwrap();
+        assert_eq!(build_requests(&client), 1);
+
+        if let Some((_, checked)) = client.build_id.lock().await.as_mut() {
+            *checked = Instant::now()
+                .checked_sub(BUILD_ID_MAX_AGE)
+                .expect("monotonic clock should be past the max age");
         }
+        let _: Vec<u32> = client.get_cached(url).await.unwrap();
+        assert_eq!(build_requests(&client), 2);
+
+        cache.prune(1).unwrap();
+        assert!(cache.read(115267, url).is_none());
+        std::fs::remove_dir_all(dir).unwrap();
     }
-}
 
-impl PaginationParams {
-    /// Creates new pagination parameters.
-    pub fn new(page: usize, page_size: usize) -> Self {
-        Self { page, page_size }
+    #[tokio::test]
+    async fn request_timeout_and_deadline() {
+        let client = Client::builder()
+            .transport(SlowTransport)
+            .timeout(Duration::from_millis(10))
+            .retry_policy(RetryPolicy::none())
+            .build()
+            .unwrap();
+
+        let err = client
+            .get::<Vec<u32>>("https://example.com/v2/items")
+            .await
+            .unwrap_err();
+        assert!(matches!(err, GetError::Http(e) if e.is_timeout()));
+
+        let client = Client::builder().transport(SlowTransport).build().unwrap();
+        let err = client
+            .get_all_pages_within::<u32>(
+                "https://example.com/v2/items",
+                PaginationParams::default(),
+                Duration::from_millis(10),
+            )
+            .await
+            .unwrap_err();
+        assert!(err.items.is_empty());
+        assert!(matches!(err.error, PaginatedGetError::DeadlineExceeded(_)));
     }
 
-    /// Creates parameters for the first page with a specific size.
-    pub fn first(page_size: usize) -> Self {
-        Self { page: 0, page_size }
+    /// Serves /v2/tokeninfo for a key with only the 'account' permission.
+    #[derive(Debug)]
+    struct AccountOnlyKey;
+
+    impl Transport for AccountOnlyKey {
+        fn send(&self, _request: transport::Request) -> transport::TransportFuture<'_> {
+            Box::pin(async {
+                Ok(transport::Response {
+                    status: reqwest::StatusCode::OK,
+                    headers: HeaderMap::new(),
+                    body: Bytes::from_static(
+                        br#"{"id": "key", "name": "test", "type": "APIKey", "permissions": ["account"]}"#,
+                    ),
+                })
+            })
+        }
     }
 
-    /// Formats the parameters as a query string fragment (without leading '?').
-    pub fn to_query_string(&self) -> String {
-        format!("page={}&page_size={}", self.page, self.page_size)
+    #[tokio::test]
+    async fn verify_permissions_reports_missing_and_caches() {
+        use crate::api::tokeninfo::Permission;
+
+        let client = Client::builder()
+            .token("secret")
+            .transport(AccountOnlyKey)
+            .build()
+            .unwrap();
+
+        client
+            .verify_permissions(&[Permission::Account])
+            .await
+            .unwrap();
+        let err = client
+            .verify_permissions(&[Permission::Account, Permission::Tradingpost])
+            .await
+            .unwrap_err();
+
+        assert!(matches!(
+            err,
+            VerifyPermissionsError::MissingPermissions(missing) if missing == [Permission::Tradingpost]
+        ));
+        assert_eq!(client.metrics().requests(), 1);
     }
 
-    /// Gets the parameters for the next page.
-    pub fn next(&self) -> PaginationParams {
-        PaginationParams {
-            page: self.page + 1,
-            page_size: self.page_size,
+    /// Adds a header to requests and counts responses.
+    #[derive(Debug, Default)]
+    struct Tagging {
+        responses: std::sync::atomic::AtomicUsize,
+    }
+
+    impl Middleware for Tagging {
+        fn on_request(&self, request: &mut transport::Request) {
+            request
+                .headers
+                .insert("X-Tag", HeaderValue::from_static("tagged"));
+        }
+
+        fn on_response(&self, request: &transport::Request, _response: &mut transport::Response) {
+            assert!(request.headers.contains_key("X-Tag"));
+            self.responses
+                .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
         }
     }
-}
 
-/// Metadata extracted from paginated API response headers.
-#[derive(Debug, Clone, Copy)]
-pub struct PaginationMetadata {
-    /// Number of entries per page (obtained from response).
-    pub page_size: usize,
-    /// Total number of pages available (obtained from response).
-    pub page_total: usize,
-    /// Number of results in the current response (obtained from response).
-    pub result_count: usize,
-    /// Total number of results across all pages (obtained from response).
-    pub result_total: usize,
-}
+    #[tokio::test]
+    async fn middleware_sees_requests_and_responses() {
+        let tagging = std::sync::Arc::new(Tagging::default());
 
-/// Represents a paginated response, containing both the data and pagination metadata.
-#[derive(Debug, Clone)]
-pub struct Paginated<T> {
-    /// The actual data returned by the API for the current page.
-    pub data: T,
-    /// Pagination metadata extracted from response headers.
-    pub metadata: PaginationMetadata,
+        let client = Client::builder()
+            .token("secret")
+            .transport(CannedTransport)
+            .middleware(tagging.clone())
+            .build()
+            .unwrap();
+
+        let _: Vec<u32> = client.get("https://example.com/v2/items").await.unwrap();
+        let _: Vec<u32> = client.get("https://example.com/v2/items").await.unwrap();
+
+        assert_eq!(
+            tagging.responses.load(std::sync::atomic::Ordering::Relaxed),
+            2
+        );
+    }
+
+    #[test]
+    fn only_server_errors_are_retried() {
+        let policy = RetryPolicy::default();
+
+        assert!(policy.should_retry_status(reqwest::StatusCode::BAD_GATEWAY));
+        assert!(!policy.should_retry_status(reqwest::StatusCode::NOT_FOUND));
+    }
 }
 
 pub mod rate_limiter {
-    use std::cell::Cell;
-    use std::time::{Duration, Instant};
-    use tracing::instrument;
+    use crate::runtime::{self, Instant};
+    use std::collections::VecDeque;
+    use std::sync::Mutex;
+    use std::time::Duration;
 
     /// A lazy token bucket rate limiter for async Rust code.
-    /// Not thread-safe - designed for use in a single task.
+    /// Thread-safe: a single limiter can be shared by concurrent tasks. Acquirers can be
+    /// given a [`Priority`], with waiting acquirers holding back those of lower priority.
+    ///
+    /// The refill rate adapts to the API: it is halved whenever the client is rate limited
+    /// and slowly recovers with every successful response, see [`RateLimiter::slow_down`].
+    #[derive(Debug)]
     pub struct RateLimiter {
         /// Maximum capacity of tokens
         capacity: u32,
-        /// Rate at which tokens refill (tokens per second)
+        /// Configured rate at which tokens refill (tokens per second), the most the
+        /// adaptive rate recovers to
+        max_refill_rate: f64,
+        /// Whether `slow_down` and `recover` adjust the refill rate
+        adaptive: bool,
+        /// Token count and the time it was calculated at
+        state: Mutex<State>,
+    }
+
+    /// Lowest adaptive refill rate, as a fraction of the configured one
+    const MIN_RATE_FRACTION: f64 = 0.05;
+    /// Refill rate regained per successful response, as a fraction of the configured one
+    const RECOVERY_FRACTION: f64 = 0.01;
+
+    /// Priority class of an acquirer. Waiting acquirers hold back all acquirers of lower
+    /// priority, so e.g. interactive lookups aren't starved by a background crawl.
+    #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub enum Priority {
+        /// Bulk work that can yield to everything else.
+        Background,
+        #[default]
+        Normal,
+        /// Requests someone is actively waiting on.
+        Interactive,
+    }
+
+    /// Shortest time to sleep between attempts to acquire tokens
+    const MIN_WAIT: Duration = Duration::from_millis(1);
+
+    #[derive(Debug)]
+    struct State {
+        /// Current rate at which tokens refill (tokens per second)
         refill_rate: f64,
-        /// Available tokens (lazily calculated when needed)
-        available_tokens: Cell<f64>,
+        /// Available tokens (lazily calculated when needed). Negative while acquirers
+        /// are waiting for reserved tokens to refill.
+        available_tokens: f64,
         /// Last time tokens were calculated
-        last_update: Cell<Instant>,
+        last_update: Instant,
+        /// Waiting acquirers per priority, as `(ticket, tokens)` in arrival order
+        queues: [VecDeque<(u64, u32)>; 3],
+        /// Ticket handed to the next waiting acquirer
+        next_ticket: u64,
+    }
+
+    impl State {
+        /// Whether acquirers of higher priority are waiting
+        fn outranked(&self, priority: Priority) -> bool {
+            self.queues[priority as usize + 1..]
+                .iter()
+                .any(|queue| !queue.is_empty())
+        }
+
+        /// Whether `ticket` (or a new acquirer, if `None`) is next in line for `priority`
+        fn is_next(&self, priority: Priority, ticket: Option<u64>) -> bool {
+            !self.outranked(priority)
+                && self.queues[priority as usize]
+                    .front()
+                    .is_none_or(|&(front, _)| Some(front) == ticket)
+        }
+
+        /// Tokens that acquirers served before `ticket` of `priority` are waiting for
+        fn tokens_ahead(&self, priority: Priority, ticket: u64) -> f64 {
+            let higher: u32 = self.queues[priority as usize + 1..]
+                .iter()
+                .flatten()
+                .map(|&(_, tokens)| tokens)
+                .sum();
+            let same: u32 = self.queues[priority as usize]
+                .iter()
+                .take_while(|&&(queued, _)| queued != ticket)
+                .map(|&(_, tokens)| tokens)
+                .sum();
+
+            (higher + same) as f64
+        }
+    }
+
+    /// A waiting acquirer's place in line, given up when it is done or dropped
+    struct WaitGuard<'a> {
+        limiter: &'a RateLimiter,
+        priority: Priority,
+        ticket: u64,
+    }
+
+    impl Drop for WaitGuard<'_> {
+        fn drop(&mut self) {
+            let mut state = self
+                .limiter
+                .state
+                .lock()
+                .expect("rate limiter lock poisoned");
+            let queue = &mut state.queues[self.priority as usize];
+            if let Some(position) = queue.iter().position(|&(ticket, _)| ticket == self.ticket) {
+                queue.remove(position);
+            }
+        }
     }
 
     impl RateLimiter {
@@ -390,22 +2740,72 @@ pub mod rate_limiter {
             tracing::info!(capacity, tokens_per_second, "Creating new AsyncRateLimiter");
             RateLimiter {
                 capacity,
-                refill_rate: tokens_per_second,
-                available_tokens: Cell::new(0.),
-                last_update: Cell::new(Instant::now()),
+                max_refill_rate: tokens_per_second,
+                adaptive: true,
+                state: Mutex::new(State {
+                    refill_rate: tokens_per_second,
+                    available_tokens: 0.,
+                    last_update: Instant::now(),
+                    queues: Default::default(),
+                    next_ticket: 0,
+                }),
+            }
+        }
+
+        /// Keeps the refill rate fixed at the configured one, ignoring
+        /// [`RateLimiter::slow_down`] and [`RateLimiter::recover`].
+        pub fn with_fixed_rate(mut self) -> Self {
+            self.adaptive = false;
+            self
+        }
+
+        /// The current refill rate, in tokens per second.
+        pub fn refill_rate(&self) -> f64 {
+            self.state
+                .lock()
+                .expect("rate limiter lock poisoned")
+                .refill_rate
+        }
+
+        /// Halves the refill rate, down to 5% of the configured one. Called by the client
+        /// when the API rate limits it or reports the limit as exhausted.
+        pub fn slow_down(&self) {
+            if !self.adaptive {
+                return;
+            }
+
+            let mut state = self.current_state();
+            let min_rate = self.max_refill_rate * MIN_RATE_FRACTION;
+            state.refill_rate = (state.refill_rate / 2.0).max(min_rate);
+            tracing::info!(refill_rate = state.refill_rate, "Slowed down rate limiter");
+        }
+
+        /// Raises the refill rate by 1% of the configured one, up to the configured one.
+        /// Called by the client after every successful response.
+        pub fn recover(&self) {
+            if !self.adaptive {
+                return;
+            }
+
+            let mut state = self.current_state();
+            if state.refill_rate < self.max_refill_rate {
+                state.refill_rate = (state.refill_rate + self.max_refill_rate * RECOVERY_FRACTION)
+                    .min(self.max_refill_rate);
+                tracing::trace!(refill_rate = state.refill_rate, "Recovered rate limiter");
             }
         }
 
-        /// Calculate current token count based on elapsed time
-        fn calculate_current_tokens(&self) {
+        /// Lock the state, with the current token count calculated from elapsed time
+        fn current_state(&self) -> std::sync::MutexGuard<'_, State> {
+            let mut state = self.state.lock().expect("rate limiter lock poisoned");
+
             let now = Instant::now();
-            let last = self.last_update.get();
-            let elapsed = now.duration_since(last).as_secs_f64();
+            let elapsed = now.duration_since(state.last_update).as_secs_f64();
 
             if elapsed > 0.0 {
                 // Calculate new tokens based on elapsed time
-                let new_tokens = self.refill_rate * elapsed;
-                let current = self.available_tokens.get();
+                let new_tokens = state.refill_rate * elapsed;
+                let current = state.available_tokens;
 
                 // Update available tokens (capped at capacity)
                 let updated = (current + new_tokens).min(self.capacity as f64);
@@ -418,112 +2818,169 @@ pub mod rate_limiter {
                     "Refreshed token bucket"
                 );
 
-                self.available_tokens.set(dbg!(updated));
-                self.last_update.set(now);
+                state.available_tokens = updated;
+                state.last_update = now;
             }
+
+            state
         }
 
-        /// Try to acquire tokens immediately without waiting
+        /// Try to acquire tokens immediately without waiting. Fails while other acquirers
+        /// are waiting, so they aren't starved.
         /// Returns true if successful, false if not enough tokens
-        #[instrument(skip(self), fields(capacity = self.capacity, available = self.available()))]
+        #[cfg_attr(
+            feature = "tracing",
+            tracing::instrument(skip(self), fields(capacity = self.capacity, available = self.available()))
+        )]
         pub fn try_acquire(&self, tokens: u32) -> bool {
-            self.calculate_current_tokens();
+            let mut state = self.current_state();
 
-            let available = self.available_tokens.get();
-            if available < tokens as f64 {
-                tracing::info!(requested = tokens, available, "Rate limit exceeded");
+            if !self.try_take(&mut state, tokens, Priority::Normal, None) {
+                tracing::info!(
+                    requested = tokens,
+                    available = state.available_tokens,
+                    "Rate limit exceeded"
+                );
                 return false;
             }
 
-            self.available_tokens.set(available - tokens as f64);
             tracing::trace!(
                 tokens,
-                remaining = self.available_tokens.get(),
+                remaining = state.available_tokens,
                 "Tokens acquired"
             );
             true
         }
 
-        /// Acquire specified number of tokens, waiting if necessary
-        pub async fn acquire(&self, tokens: u32) {
-            self.calculate_current_tokens();
+        /// How long until the acquirer holding `ticket` could take `tokens`: until enough
+        /// have refilled for it and every acquirer ahead of it.
+        fn wait_time(
+            &self,
+            state: &State,
+            tokens: u32,
+            priority: Priority,
+            ticket: u64,
+        ) -> Duration {
+            let tokens_needed =
+                state.tokens_ahead(priority, ticket) + tokens as f64 - state.available_tokens;
+
+            Duration::from_secs_f64(tokens_needed.max(0.0) / state.refill_rate).max(MIN_WAIT)
+        }
 
-            let available = self.available_tokens.get();
-            if available >= tokens as f64 {
-                // We have enough tokens available
-                self.available_tokens.set(available - tokens as f64);
-                tracing::trace!(tokens, "Tokens acquired immediately");
-                return;
+        /// Take `tokens` if they are available and `ticket` is next in line
+        fn try_take(
+            &self,
+            state: &mut State,
+            tokens: u32,
+            priority: Priority,
+            ticket: Option<u64>,
+        ) -> bool {
+            if !state.is_next(priority, ticket) || state.available_tokens < tokens as f64 {
+                return false;
             }
 
-            // Calculate tokens needed and wait time
-            let tokens_needed = tokens as f64 - available;
-            let wait_time = Duration::from_secs_f64(tokens_needed / self.refill_rate);
-
-            tracing::trace!(
-                tokens,
-                tokens_needed,
-                wait_time_ms = wait_time.as_millis(),
-                "Waiting for token refill"
-            );
-
-            // Use all currently available tokens
-            self.available_tokens.set(0.0);
+            state.available_tokens -= tokens as f64;
+            true
+        }
 
-            // Wait for remaining tokens to become available
-            tokio::time::sleep(wait_time).await;
+        /// Acquire specified number of tokens, waiting if necessary
+        pub async fn acquire(&self, tokens: u32) {
+            self.acquire_with_priority(tokens, Priority::Normal).await
+        }
 
-            // Update time after waiting
-            self.last_update.set(Instant::now());
-            tracing::trace!(tokens, "Tokens acquired after waiting");
+        /// Acquire specified number of tokens, waiting if necessary. Waiting acquirers are
+        /// served in order of arrival, and hold back all acquirers of lower priority until
+        /// they got their tokens.
+        pub async fn acquire_with_priority(&self, tokens: u32, priority: Priority) {
+            self.acquire_until(tokens, priority, None).await;
         }
 
         /// Acquire tokens with a timeout
         /// Returns true if tokens were acquired, false if timeout reached
         pub async fn acquire_with_timeout(&self, tokens: u32, timeout: Duration) -> bool {
-            self.calculate_current_tokens();
-
-            let available = self.available_tokens.get();
-            if available >= tokens as f64 {
-                // We have enough tokens available
-                self.available_tokens.set(available - tokens as f64);
-                tracing::trace!(tokens, "Tokens acquired immediately with timeout");
-                return true;
-            }
+            self.acquire_until(tokens, Priority::Normal, Some(Instant::now() + timeout))
+                .await
+        }
 
-            // Calculate how long we'd need to wait
-            let tokens_needed = tokens as f64 - available;
-            let required_wait = Duration::from_secs_f64(tokens_needed / self.refill_rate);
+        /// Waits in line for `tokens`, re-checking the bucket every time it wakes up.
+        /// Gives up, returning false, once `deadline` can't be met.
+        async fn acquire_until(
+            &self,
+            tokens: u32,
+            priority: Priority,
+            deadline: Option<Instant>,
+        ) -> bool {
+            let mut waiting: Option<WaitGuard<'_>> = None;
+
+            loop {
+                let wait_time = {
+                    let mut state = self.current_state();
+                    let ticket = waiting.as_ref().map(|guard| guard.ticket);
+                    if self.try_take(&mut state, tokens, priority, ticket) {
+                        break;
+                    }
+
+                    let ticket = match ticket {
+                        Some(ticket) => ticket,
+                        None => {
+                            let ticket = state.next_ticket;
+                            state.next_ticket += 1;
+                            state.queues[priority as usize].push_back((ticket, tokens));
+                            waiting = Some(WaitGuard {
+                                limiter: self,
+                                priority,
+                                ticket,
+                            });
+                            ticket
+                        }
+                    };
+                    let wait_time = self.wait_time(&state, tokens, priority, ticket);
+
+                    if deadline.is_some_and(|deadline| Instant::now() + wait_time > deadline) {
+                        tracing::trace!(
+                            required_wait_ms = wait_time.as_millis(),
+                            "Timeout too short for required wait"
+                        );
+                        drop(state);
+                        return false;
+                    }
+                    wait_time
+                };
 
-            if required_wait > timeout {
                 tracing::trace!(
-                    required_wait_ms = required_wait.as_millis(),
-                    timeout_ms = timeout.as_millis(),
-                    "Timeout too short for required wait"
+                    tokens,
+                    ?priority,
+                    wait_time_ms = wait_time.as_millis(),
+                    "Waiting for token refill"
                 );
-                return false; // Would exceed timeout
-            }
 
-            // Use all available tokens and wait
-            self.available_tokens.set(0.0);
+                runtime::sleep(wait_time).await;
+            }
 
-            tracing::trace!(
-                tokens,
-                wait_time_ms = required_wait.as_millis(),
-                "Waiting for token refill with timeout"
-            );
+            if waiting.is_some() {
+                tracing::trace!(tokens, "Tokens acquired after waiting");
+            } else {
+                tracing::trace!(tokens, "Tokens acquired immediately");
+            }
+            true
+        }
 
-            tokio::time::sleep(required_wait).await;
-            self.last_update.set(Instant::now());
-            tracing::trace!(tokens, "Tokens acquired after waiting with timeout");
+        /// Empty the bucket and delay refilling by `duration`, e.g. after the server asked us
+        /// to back off. Waiting acquirers will need to wait out the full duration.
+        pub fn drain_for(&self, duration: Duration) {
+            let mut state = self.current_state();
 
-            true
+            let deficit = state.refill_rate * duration.as_secs_f64();
+            state.available_tokens = -deficit;
+            tracing::debug!(
+                drain_ms = duration.as_millis() as u64,
+                "Drained token bucket"
+            );
         }
 
         /// Get current available tokens (for debugging/testing)
         pub fn available(&self) -> f64 {
-            self.calculate_current_tokens();
-            self.available_tokens.get()
+            self.current_state().available_tokens
         }
     }
 
@@ -597,6 +3054,170 @@ pub mod rate_limiter {
             assert_float_eq(limiter.available(), 0.0, 0.01);
         }
 
+        #[tokio::test]
+        async fn test_drain_for_delays_refill() {
+            let limiter = RateLimiter::new(5, 2.0);
+            limiter.drain_for(Duration::from_secs(1));
+            assert_float_eq(limiter.available(), -2.0, 0.01);
+            assert!(!limiter.try_acquire(1));
+        }
+
+        #[tokio::test]
+        async fn test_concurrent_acquirers_are_spaced_out() {
+            let limiter = std::sync::Arc::new(RateLimiter::new(5, 10.0));
+            let start = Instant::now();
+
+            let tasks: Vec<_> = (0..5)
+                .map(|_| {
+                    let limiter = limiter.clone();
+                    tokio::spawn(async move { limiter.acquire(1).await })
+                })
+                .collect();
+            for task in tasks {
+                task.await.unwrap();
+            }
+
+            assert!(start.elapsed() >= Duration::from_millis(450));
+            assert_float_eq(limiter.available(), 0.0, 0.05);
+        }
+
+        #[tokio::test]
+        async fn test_higher_priority_goes_first() {
+            let limiter = std::sync::Arc::new(RateLimiter::new(5, 10.0));
+            let order = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+            let spawn = |priority| {
+                let limiter = limiter.clone();
+                let order = order.clone();
+                tokio::spawn(async move {
+                    limiter.acquire_with_priority(1, priority).await;
+                    order.lock().unwrap().push(priority);
+                })
+            };
+
+            let background = spawn(Priority::Background);
+            sleep(Duration::from_millis(20)).await;
+            let interactive = spawn(Priority::Interactive);
+
+            background.await.unwrap();
+            interactive.await.unwrap();
+
+            assert_eq!(
+                *order.lock().unwrap(),
+                vec![Priority::Interactive, Priority::Background]
+            );
+        }
+
+        #[tokio::test]
+        async fn test_waiters_are_served_in_arrival_order() {
+            let limiter = std::sync::Arc::new(RateLimiter::new(5, 20.0));
+            let order = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+
+            let mut tasks = Vec::new();
+            for i in 0..10 {
+                let limiter = limiter.clone();
+                let order = order.clone();
+                // Later arrivals ask for fewer tokens, so barging would let them go first
+                let tokens = if i % 2 == 0 { 3 } else { 1 };
+                tasks.push(tokio::spawn(async move {
+                    limiter.acquire(tokens).await;
+                    order.lock().unwrap().push(i);
+                }));
+                sleep(Duration::from_millis(2)).await;
+            }
+            for task in tasks {
+                task.await.unwrap();
+            }
+
+            assert_eq!(*order.lock().unwrap(), (0..10).collect::<Vec<_>>());
+        }
+
+        #[tokio::test]
+        async fn test_cancelled_waiter_gives_up_its_place() {
+            let limiter = std::sync::Arc::new(RateLimiter::new(5, 10.0));
+
+            let first = tokio::spawn({
+                let limiter = limiter.clone();
+                async move { limiter.acquire(5).await }
+            });
+            sleep(Duration::from_millis(10)).await;
+            first.abort();
+            let _ = first.await;
+
+            let start = Instant::now();
+            limiter.acquire(1).await;
+            assert!(start.elapsed() < Duration::from_millis(250));
+        }
+
+        #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+        async fn test_stress_never_exceeds_rate() {
+            const CAPACITY: u32 = 10;
+            const RATE: f64 = 200.0;
+
+            let limiter = std::sync::Arc::new(RateLimiter::new(CAPACITY, RATE));
+            let start = Instant::now();
+
+            let tasks: Vec<_> = (0..100)
+                .map(|i| {
+                    let limiter = limiter.clone();
+                    let priority = [
+                        Priority::Background,
+                        Priority::Normal,
+                        Priority::Interactive,
+                    ][i % 3];
+                    let tokens = 1 + (i % 3) as u32;
+                    tokio::spawn(async move {
+                        limiter.acquire_with_priority(tokens, priority).await;
+                        (start.elapsed(), tokens)
+                    })
+                })
+                .collect();
+
+            let mut acquired = Vec::new();
+            for task in tasks {
+                acquired.push(task.await.unwrap());
+            }
+            acquired.sort();
+
+            // The bucket starts empty, so by any point in time at most the refilled tokens
+            // can have been handed out.
+            let mut total = 0;
+            for (elapsed, tokens) in acquired {
+                total += tokens;
+                let refilled = RATE * elapsed.as_secs_f64();
+                assert!(
+                    total as f64 <= refilled + 1.0,
+                    "{} tokens acquired after {:?}, only {} refilled",
+                    total,
+                    elapsed,
+                    refilled
+                );
+            }
+            assert_eq!(total, 199);
+        }
+
+        #[test]
+        fn test_refill_rate_adapts() {
+            let limiter = RateLimiter::new(5, 10.0);
+            limiter.slow_down();
+            assert_float_eq(limiter.refill_rate(), 5.0, 0.001);
+            for _ in 0..10 {
+                limiter.slow_down();
+            }
+            assert_float_eq(limiter.refill_rate(), 0.5, 0.001);
+
+            limiter.recover();
+            assert_float_eq(limiter.refill_rate(), 0.6, 0.001);
+            for _ in 0..200 {
+                limiter.recover();
+            }
+            assert_float_eq(limiter.refill_rate(), 10.0, 0.001);
+
+            let limiter = RateLimiter::new(5, 10.0).with_fixed_rate();
+            limiter.slow_down();
+            assert_float_eq(limiter.refill_rate(), 10.0, 0.001);
+        }
+
         #[tokio::test]
         async fn test_available_tokens_refill() {
             let limiter = RateLimiter::new(5, 1.0);
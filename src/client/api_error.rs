@@ -0,0 +1,114 @@
+//! Errors reported by the API in the body of unsuccessful responses.
+
+use reqwest::StatusCode;
+
+/// An error reported by the API, classified from the status and the `{"text": ...}` (or
+/// `{"error": ...}`) body of the response.
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum ApiError {
+    #[error("invalid API key: {0}")]
+    InvalidKey(String),
+    /// The API key lacks a permission the endpoint requires.
+    #[error("API key is missing the '{0}' permission")]
+    MissingScope(String),
+    #[error("not found: {0}")]
+    NotFound(String),
+    #[error("rate limited: {0}")]
+    RateLimited(String),
+    /// The API, or the endpoint, is temporarily unavailable.
+    #[error("API unavailable: {0}")]
+    Maintenance(String),
+    #[error("{0}")]
+    Other(String),
+}
+
+#[derive(serde::Deserialize)]
+struct ErrorBody {
+    text: Option<String>,
+    error: Option<String>,
+}
+
+impl ApiError {
+    /// Classifies an unsuccessful response from its status and body.
+    pub fn from_response(status: StatusCode, body: &str) -> Self {
+        let text = serde_json::from_str::<ErrorBody>(body)
+            .ok()
+            .and_then(|body| body.text.or(body.error))
+            .unwrap_or_else(|| body.trim().to_string());
+        let lowercase = text.to_lowercase();
+
+        if let Some(scope) = lowercase.split("requires scope ").nth(1) {
+            let scope = scope.split_whitespace().next().unwrap_or_default();
+            return ApiError::MissingScope(scope.to_string());
+        }
+
+        match status {
+            StatusCode::UNAUTHORIZED | StatusCode::FORBIDDEN
+                if lowercase.contains("invalid") || status == StatusCode::UNAUTHORIZED =>
+            {
+                ApiError::InvalidKey(text)
+            }
+            StatusCode::NOT_FOUND => ApiError::NotFound(text),
+            StatusCode::TOO_MANY_REQUESTS => ApiError::RateLimited(text),
+            _ if lowercase.contains("api is disabled") || lowercase.contains("api not active") => {
+                ApiError::Maintenance(text)
+            }
+            _ if lowercase.contains("invalid key")
+                || lowercase.contains("invalid access token") =>
+            {
+                ApiError::InvalidKey(text)
+            }
+            _ => ApiError::Other(text),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_error_bodies() {
+        assert_eq!(
+            ApiError::from_response(
+                StatusCode::UNAUTHORIZED,
+                r#"{"text": "Invalid access token"}"#
+            ),
+            ApiError::InvalidKey("Invalid access token".into())
+        );
+        assert_eq!(
+            ApiError::from_response(
+                StatusCode::FORBIDDEN,
+                r#"{"text": "requires scope tradingpost"}"#
+            ),
+            ApiError::MissingScope("tradingpost".into())
+        );
+        assert_eq!(
+            ApiError::from_response(StatusCode::NOT_FOUND, r#"{"text": "no such id"}"#),
+            ApiError::NotFound("no such id".into())
+        );
+        assert_eq!(
+            ApiError::from_response(
+                StatusCode::SERVICE_UNAVAILABLE,
+                r#"{"text": "API not active"}"#
+            ),
+            ApiError::Maintenance("API not active".into())
+        );
+        assert_eq!(
+            ApiError::from_response(StatusCode::BAD_GATEWAY, r#"{"text": "API is disabled"}"#),
+            ApiError::Maintenance("API is disabled".into())
+        );
+        assert_eq!(
+            ApiError::from_response(StatusCode::BAD_REQUEST, r#"{"error": "bad request"}"#),
+            ApiError::Other("bad request".into())
+        );
+        assert_eq!(
+            ApiError::from_response(StatusCode::BAD_GATEWAY, "<html>bad gateway</html>"),
+            ApiError::Other("<html>bad gateway</html>".into())
+        );
+        assert_eq!(
+            ApiError::from_response(StatusCode::SERVICE_UNAVAILABLE, "upstream overloaded"),
+            ApiError::Other("upstream overloaded".into())
+        );
+    }
+}
@@ -0,0 +1,209 @@
+//! Providers of the API key requests are authenticated with, e.g. to use short-lived
+//! subtokens instead of the account's API key.
+
+use std::{borrow::Cow, fmt, time::Duration};
+
+use reqwest::header::HeaderValue;
+
+use super::{Client, GetError};
+use crate::{
+    api::{createsubtoken, tokeninfo::Permission},
+    runtime::{Instant, SystemTime},
+};
+
+/// The future returned by [`AuthProvider::authorization`]. Not `Send` on wasm, like
+/// [`TransportFuture`](super::transport::TransportFuture).
+#[cfg(not(target_arch = "wasm32"))]
+pub type AuthFuture<'a> =
+    futures_util::future::BoxFuture<'a, Result<Option<HeaderValue>, GetError>>;
+#[cfg(target_arch = "wasm32")]
+pub type AuthFuture<'a> =
+    futures_util::future::LocalBoxFuture<'a, Result<Option<HeaderValue>, GetError>>;
+
+/// Supplies the `Authorization` header of every request made through a client, see
+/// [`ClientBuilder::auth_provider`](super::ClientBuilder::auth_provider). Requests made
+/// with [`Client::get_with_token`] don't go through the provider.
+pub trait AuthProvider: fmt::Debug + Send + Sync {
+    /// The header to send, or `None` to send the client's API key. Called before every
+    /// attempt of a request, so it should be cheap once the header is known.
+    fn authorization<'a>(&'a self, client: &'a Client) -> AuthFuture<'a>;
+}
+
+/// Authenticates requests with subtokens of a root API key, created through
+/// /v2/createsubtoken and replaced before they expire. The root key is only sent to create
+/// subtokens, so a leaked request log only exposes a short-lived token.
+pub struct SubtokenRotation {
+    root_token: Cow<'static, str>,
+    lifetime: Duration,
+    permissions: Vec<Permission>,
+    urls: Vec<String>,
+    /// The current subtoken and when it should be replaced.
+    current: tokio::sync::Mutex<Option<(HeaderValue, Instant)>>,
+}
+
+impl fmt::Debug for SubtokenRotation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SubtokenRotation")
+            .field("lifetime", &self.lifetime)
+            .field("permissions", &self.permissions)
+            .field("urls", &self.urls)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SubtokenRotation {
+    /// Creates subtokens of `root_token` that expire after `lifetime`, with every
+    /// permission of the root token.
+    pub fn new(root_token: impl Into<Cow<'static, str>>, lifetime: Duration) -> Self {
+        Self {
+            root_token: root_token.into(),
+            lifetime,
+            permissions: Vec::new(),
+            urls: Vec::new(),
+            current: tokio::sync::Mutex::new(None),
+        }
+    }
+
+    /// Restricts subtokens to `permissions`, which the root token must grant.
+    pub fn permissions(mut self, permissions: impl IntoIterator<Item = Permission>) -> Self {
+        self.permissions = permissions.into_iter().collect();
+        self
+    }
+
+    /// Restricts subtokens to `urls`, e.g. `/v2/account`.
+    pub fn urls<S: Into<String>>(mut self, urls: impl IntoIterator<Item = S>) -> Self {
+        self.urls = urls.into_iter().map(Into::into).collect();
+        self
+    }
+
+    async fn subtoken(&self, client: &Client) -> Result<HeaderValue, GetError> {
+        let mut current = self.current.lock().await;
+        let valid = current
+            .as_ref()
+            .filter(|(_, renew_at)| Instant::now() < *renew_at);
+        if let Some((authorization, _)) = valid {
+            return Ok(authorization.clone());
+        }
+
+        let expires = SystemTime::now() + self.lifetime;
+        let seconds = expires
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let urls: Vec<&str> = self.urls.iter().map(String::as_str).collect();
+        let url =
+            createsubtoken::subtoken_url(client, &format_utc(seconds), &self.permissions, &urls);
+
+        tracing::debug!(lifetime_secs = self.lifetime.as_secs(), "Creating subtoken");
+        let created: createsubtoken::Subtoken =
+            client.get_with_token(&url, &self.root_token).await?;
+
+        let mut authorization = HeaderValue::from_str(&format!("Bearer {}", created.subtoken))
+            .map_err(GetError::InvalidToken)?;
+        authorization.set_sensitive(true);
+
+        // Renew once 90% of the lifetime has passed, so requests in flight don't expire
+        let renew_at = Instant::now() + self.lifetime.mul_f64(0.9);
+        *current = Some((authorization.clone(), renew_at));
+
+        Ok(authorization)
+    }
+}
+
+impl AuthProvider for SubtokenRotation {
+    fn authorization<'a>(&'a self, client: &'a Client) -> AuthFuture<'a> {
+        Box::pin(async move { self.subtoken(client).await.map(Some) })
+    }
+}
+
+/// Formats seconds since the unix epoch as ISO-8601 UTC timestamp, e.g.
+/// `2025-01-01T00:00:00Z`.
+fn format_utc(seconds: u64) -> String {
+    let days = (seconds / 86_400) as i64;
+    let time = seconds % 86_400;
+
+    // Civil date from days since the epoch, see https://howardhinnant.github.io/date_algorithms.html
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z",
+        year,
+        month,
+        day,
+        time / 3600,
+        time / 60 % 60,
+        time % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::transport::{Request, Response, Transport, TransportFuture};
+
+    #[test]
+    fn formats_utc_timestamps() {
+        assert_eq!(format_utc(0), "1970-01-01T00:00:00Z");
+        assert_eq!(format_utc(951_782_400), "2000-02-29T00:00:00Z");
+        assert_eq!(format_utc(1_735_689_599), "2024-12-31T23:59:59Z");
+    }
+
+    /// Creates subtokens for the root key, and answers other requests with the key used.
+    #[derive(Debug)]
+    struct SubtokenServer;
+
+    impl Transport for SubtokenServer {
+        fn send(&self, request: Request) -> TransportFuture<'_> {
+            Box::pin(async move {
+                let auth = request.headers["authorization"]
+                    .to_str()
+                    .unwrap()
+                    .to_string();
+                let body = if request.url.contains("/v2/createsubtoken") {
+                    assert_eq!(auth, "Bearer root");
+                    assert!(request.url.contains("permissions=account%2Ctradingpost"));
+                    serde_json::json!({ "subtoken": "sub" })
+                } else {
+                    serde_json::json!(auth)
+                };
+
+                Ok(Response {
+                    status: reqwest::StatusCode::OK,
+                    headers: Default::default(),
+                    body: serde_json::to_vec(&body).unwrap().into(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn requests_use_rotated_subtoken() {
+        let client = Client::builder()
+            .transport(SubtokenServer)
+            .auth_provider(
+                SubtokenRotation::new("root", Duration::from_secs(3600))
+                    .permissions([Permission::Account, Permission::Tradingpost]),
+            )
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let auth: String = client.get("https://example.com/v2/account").await.unwrap();
+            assert_eq!(auth, "Bearer sub");
+        }
+        assert_eq!(client.metrics().requests(), 3);
+    }
+}
@@ -0,0 +1,91 @@
+//! A persistent cache of response bodies, for endpoints that only change with game builds.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+/// Response bodies stored as files in a directory, one subdirectory per game build. Used by
+/// [`Client::get_cached`](super::Client::get_cached) and with it the bulk fetchers of
+/// static data (e.g. `items::get_items_chunked`), so that repeated crawls of items, recipes
+/// or skins only download them again after a game update.
+#[derive(Debug, Clone)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+impl DiskCache {
+    /// Uses `dir` as cache directory, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be created.
+    pub fn new(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir)?;
+        Ok(Self { dir })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn path(&self, build_id: u32, url: &str) -> PathBuf {
+        self.dir
+            .join(build_id.to_string())
+            .join(format!("{:016x}.json", fnv1a(url.as_bytes())))
+    }
+
+    /// The cached body for `url` at `build_id`, if any.
+    pub(super) fn read(&self, build_id: u32, url: &str) -> Option<Vec<u8>> {
+        fs::read(self.path(build_id, url)).ok()
+    }
+
+    /// Stores the body for `url` at `build_id`.
+    pub(super) fn write(&self, build_id: u32, url: &str, body: &[u8]) -> io::Result<()> {
+        let path = self.path(build_id, url);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        // Write to a temporary file first, so readers never see a partial body.
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, body)?;
+        fs::rename(tmp, path)
+    }
+
+    /// Removes the entries of every build except `build_id`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be read or an entry can't be removed.
+    pub fn prune(&self, build_id: u32) -> io::Result<()> {
+        let keep = build_id.to_string();
+
+        for entry in fs::read_dir(&self.dir)? {
+            let entry = entry?;
+            if entry.file_type()?.is_dir() && entry.file_name() != keep.as_str() {
+                fs::remove_dir_all(entry.path())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Removes every entry.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be cleared.
+    pub fn clear(&self) -> io::Result<()> {
+        fs::remove_dir_all(&self.dir)?;
+        fs::create_dir_all(&self.dir)
+    }
+}
+
+/// 64-bit FNV-1a, used for file names since it is stable across Rust versions.
+pub(super) fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf29ce484222325, |hash, byte| {
+        (hash ^ *byte as u64).wrapping_mul(0x100000001b3)
+    })
+}
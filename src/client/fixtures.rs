@@ -0,0 +1,198 @@
+//! Recording responses to disk and replaying them without network access, for offline
+//! development and reproducible tests against real API snapshots.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use reqwest::{
+    header::{HeaderMap, HeaderName, HeaderValue},
+    StatusCode,
+};
+
+use super::{
+    disk_cache::fnv1a,
+    transport::{Request, Response, Transport, TransportError, TransportFuture},
+};
+
+/// A recorded response, stored as one JSON file per url.
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Fixture {
+    url: String,
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+
+impl Fixture {
+    fn from_response(url: &str, response: &Response) -> Self {
+        Self {
+            url: url.to_string(),
+            status: response.status.as_u16(),
+            headers: response
+                .headers
+                .iter()
+                .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.into())))
+                .collect(),
+            body: String::from_utf8_lossy(&response.body).into_owned(),
+        }
+    }
+
+    fn into_response(self) -> Result<Response, TransportError> {
+        let invalid = |e: Box<dyn std::error::Error + Send + Sync>| TransportError::Other(e);
+
+        let mut headers = HeaderMap::new();
+        for (name, value) in self.headers {
+            headers.append(
+                HeaderName::try_from(name).map_err(|e| invalid(e.into()))?,
+                HeaderValue::try_from(value).map_err(|e| invalid(e.into()))?,
+            );
+        }
+
+        Ok(Response {
+            status: StatusCode::from_u16(self.status).map_err(|e| invalid(e.into()))?,
+            headers,
+            body: self.body.into(),
+        })
+    }
+}
+
+fn fixture_path(dir: &Path, url: &str) -> PathBuf {
+    dir.join(format!("{:016x}.json", fnv1a(url.as_bytes())))
+}
+
+/// Sends requests through another transport, storing every response in a directory for
+/// [`ReplayTransport`]. A url recorded twice keeps its latest response.
+#[derive(Debug)]
+pub struct RecordingTransport {
+    inner: Box<dyn Transport>,
+    dir: PathBuf,
+}
+
+impl RecordingTransport {
+    /// Records the responses of `inner` to `dir`, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the directory can't be created.
+    pub fn new(inner: impl Transport + 'static, dir: impl Into<PathBuf>) -> io::Result<Self> {
+        Self::boxed(Box::new(inner), dir.into())
+    }
+
+    pub(super) fn boxed(inner: Box<dyn Transport>, dir: PathBuf) -> io::Result<Self> {
+        fs::create_dir_all(&dir)?;
+        Ok(Self { inner, dir })
+    }
+
+    fn record(&self, url: &str, response: &Response) -> io::Result<()> {
+        let fixture = serde_json::to_vec_pretty(&Fixture::from_response(url, response))?;
+        let path = fixture_path(&self.dir, url);
+
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, fixture)?;
+        fs::rename(tmp, path)
+    }
+}
+
+impl Transport for RecordingTransport {
+    fn send(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let url = request.url.clone();
+            let response = self.inner.send(request).await?;
+
+            if let Err(e) = self.record(&url, &response) {
+                tracing::warn!(%url, error = %e, "Failed to record response");
+            }
+
+            Ok(response)
+        })
+    }
+}
+
+/// Serves the responses stored by [`RecordingTransport`] without network access. Requests
+/// to urls that weren't recorded fail with [`TransportError::Other`].
+#[derive(Debug, Clone)]
+pub struct ReplayTransport {
+    dir: PathBuf,
+}
+
+impl ReplayTransport {
+    /// Replays the responses recorded to `dir`.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    fn replay(&self, url: &str) -> Result<Response, TransportError> {
+        let fixture = fs::read(fixture_path(&self.dir, url)).map_err(|e| {
+            TransportError::Other(format!("no recorded response for {}: {}", url, e).into())
+        })?;
+        let fixture: Fixture =
+            serde_json::from_slice(&fixture).map_err(|e| TransportError::Other(e.into()))?;
+
+        fixture.into_response()
+    }
+}
+
+impl Transport for ReplayTransport {
+    fn send(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(async move { self.replay(&request.url) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::Client;
+
+    /// Answers every request with its url.
+    #[derive(Debug)]
+    struct EchoUrl;
+
+    impl Transport for EchoUrl {
+        fn send(&self, request: Request) -> TransportFuture<'_> {
+            Box::pin(async move {
+                let mut headers = HeaderMap::new();
+                headers.insert("X-Test", HeaderValue::from_static("recorded"));
+
+                Ok(Response {
+                    status: StatusCode::OK,
+                    headers,
+                    body: serde_json::to_vec(&request.url).unwrap().into(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn replays_recorded_responses() {
+        let dir = std::env::temp_dir().join(format!("gw2gd-fixtures-{}", std::process::id()));
+        let url = "https://example.com/v2/items?ids=1,2";
+
+        let recording = Client::builder()
+            .transport(EchoUrl)
+            .record_to(&dir)
+            .build()
+            .unwrap();
+        let recorded: String = recording.get(url).await.unwrap();
+
+        let replay = ReplayTransport::new(&dir);
+        let response = replay
+            .send(Request {
+                url: url.to_string(),
+                headers: HeaderMap::new(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(response.headers["X-Test"], "recorded");
+
+        let replaying = Client::builder().replay_from(&dir).build().unwrap();
+        assert_eq!(replaying.get::<String>(url).await.unwrap(), recorded);
+        assert!(replaying
+            .get::<String>("https://example.com/v2/other")
+            .await
+            .is_err());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,216 @@
+//! Request metrics collected by a [`Client`](super::Client).
+
+use std::{collections::HashMap, sync::Mutex, time::Duration};
+
+use crate::runtime::Instant;
+
+/// Upper bounds of the latency histogram buckets. A final bucket catches everything slower.
+pub const LATENCY_BUCKETS: [Duration; 10] = [
+    Duration::from_millis(10),
+    Duration::from_millis(25),
+    Duration::from_millis(50),
+    Duration::from_millis(100),
+    Duration::from_millis(250),
+    Duration::from_millis(500),
+    Duration::from_secs(1),
+    Duration::from_millis(2500),
+    Duration::from_secs(5),
+    Duration::from_secs(10),
+];
+
+/// Distribution of request latencies.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct LatencyHistogram {
+    /// Number of requests per bucket of [`LATENCY_BUCKETS`], plus one for slower requests.
+    pub counts: [u64; LATENCY_BUCKETS.len() + 1],
+    /// Sum of all latencies.
+    pub total: Duration,
+}
+
+impl LatencyHistogram {
+    fn record(&mut self, latency: Duration) {
+        let bucket = LATENCY_BUCKETS
+            .iter()
+            .position(|bound| latency <= *bound)
+            .unwrap_or(LATENCY_BUCKETS.len());
+
+        self.counts[bucket] += 1;
+        self.total += latency;
+    }
+
+    /// Number of recorded latencies.
+    pub fn count(&self) -> u64 {
+        self.counts.iter().sum()
+    }
+
+    /// Average latency, or `None` if nothing was recorded.
+    pub fn mean(&self) -> Option<Duration> {
+        let count = self.count();
+        (count > 0).then(|| self.total.div_f64(count as f64))
+    }
+
+    /// Upper bound of the bucket containing the given quantile (between 0 and 1), or `None`
+    /// if nothing was recorded or it falls in the last, unbounded bucket.
+    pub fn quantile(&self, quantile: f64) -> Option<Duration> {
+        let count = self.count();
+        if count == 0 {
+            return None;
+        }
+
+        let target = ((count as f64) * quantile.clamp(0.0, 1.0)).ceil().max(1.0) as u64;
+        let mut seen = 0;
+        for (bucket, bucket_count) in self.counts.iter().enumerate() {
+            seen += bucket_count;
+            if seen >= target {
+                return LATENCY_BUCKETS.get(bucket).copied();
+            }
+        }
+
+        None
+    }
+}
+
+/// Metrics for a single endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct EndpointMetrics {
+    /// Requests sent, including retries.
+    pub requests: u64,
+    /// Requests that failed, either without a response or with an error status.
+    pub errors: u64,
+    /// Latency of every request, from sending it until its body was read.
+    pub latency: LatencyHistogram,
+}
+
+/// A snapshot of a client's metrics, see [`Client::metrics`](super::Client::metrics).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Metrics {
+    /// Metrics per endpoint path (e.g. `/v2/commerce/prices`), without query parameters.
+    /// Ids in the path are replaced by `:id`, e.g. `/v2/items/:id` or
+    /// `/v2/characters/:id/inventory`, so that each endpoint has one entry.
+    pub endpoints: HashMap<String, EndpointMetrics>,
+    /// Total time spent waiting for the rate limiter.
+    pub rate_limiter_wait: Duration,
+}
+
+impl Metrics {
+    /// Requests sent to all endpoints.
+    pub fn requests(&self) -> u64 {
+        self.endpoints.values().map(|e| e.requests).sum()
+    }
+
+    /// Failed requests to all endpoints.
+    pub fn errors(&self) -> u64 {
+        self.endpoints.values().map(|e| e.errors).sum()
+    }
+}
+
+/// Collects metrics as requests are made.
+#[derive(Debug, Default)]
+pub(super) struct Recorder {
+    metrics: Mutex<Metrics>,
+}
+
+impl Recorder {
+    pub(super) fn record_request(&self, url: &str, started: Instant, failed: bool) {
+        let latency = started.elapsed();
+        let mut metrics = self.metrics.lock().expect("metrics lock poisoned");
+        let endpoint = metrics.endpoints.entry(endpoint_path(url)).or_default();
+
+        endpoint.requests += 1;
+        if failed {
+            endpoint.errors += 1;
+        }
+        endpoint.latency.record(latency);
+    }
+
+    pub(super) fn record_rate_limiter_wait(&self, wait: Duration) {
+        self.metrics
+            .lock()
+            .expect("metrics lock poisoned")
+            .rate_limiter_wait += wait;
+    }
+
+    pub(super) fn snapshot(&self) -> Metrics {
+        self.metrics.lock().expect("metrics lock poisoned").clone()
+    }
+
+    pub(super) fn reset(&self) {
+        *self.metrics.lock().expect("metrics lock poisoned") = Metrics::default();
+    }
+}
+
+/// The path of `url`, without domain and query, and with ids replaced by `:id`.
+pub(super) fn endpoint_path(url: &str) -> String {
+    match reqwest::Url::parse(url) {
+        Ok(url) => normalize_ids(url.path()),
+        Err(_) => normalize_ids(url.split('?').next().unwrap_or(url)),
+    }
+}
+
+/// Replaces the id segments of `path` by `:id`: segments with digits (numeric ids, UUIDs,
+/// match ids like `1-2`) except the API version, and character names.
+fn normalize_ids(path: &str) -> String {
+    let mut previous = "";
+    let segments: Vec<&str> = path
+        .split('/')
+        .enumerate()
+        .map(|(i, segment)| {
+            let is_version = i == 1 && segment.starts_with('v');
+            let is_id = (!is_version && segment.bytes().any(|b| b.is_ascii_digit()))
+                || previous == "characters";
+            previous = segment;
+            if is_id {
+                ":id"
+            } else {
+                segment
+            }
+        })
+        .collect();
+
+    segments.join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_buckets_and_quantiles() {
+        let mut histogram = LatencyHistogram::default();
+        for ms in [5, 20, 20, 400, 20_000] {
+            histogram.record(Duration::from_millis(ms));
+        }
+
+        assert_eq!(histogram.count(), 5);
+        assert_eq!(histogram.mean(), Some(Duration::from_millis(4089)));
+        assert_eq!(histogram.quantile(0.5), Some(Duration::from_millis(25)));
+        assert_eq!(histogram.quantile(0.8), Some(Duration::from_millis(500)));
+        assert_eq!(histogram.quantile(1.0), None);
+    }
+
+    #[test]
+    fn endpoint_path_strips_domain_and_query() {
+        assert_eq!(
+            endpoint_path("https://api.guildwars2.com/v2/commerce/prices?ids=1,2"),
+            "/v2/commerce/prices"
+        );
+        assert_eq!(
+            endpoint_path("https://api.guildwars2.com/v2/items/19721"),
+            "/v2/items/:id"
+        );
+        assert_eq!(
+            endpoint_path("https://api.guildwars2.com/v2/characters/Some%20Name/inventory"),
+            "/v2/characters/:id/inventory"
+        );
+        assert_eq!(
+            endpoint_path(
+                "https://api.guildwars2.com/v2/guild/116E0C0E-0035-44A9-BB22-4AE3E23127E5/log"
+            ),
+            "/v2/guild/:id/log"
+        );
+        assert_eq!(
+            endpoint_path("https://api.guildwars2.com/v2/continents/1/floors/0/regions"),
+            "/v2/continents/:id/floors/:id/regions"
+        );
+    }
+}
@@ -0,0 +1,43 @@
+//! Hooks into every request made by a [`Client`](super::Client).
+//!
+//! Middleware is added with [`ClientBuilder::middleware`](super::ClientBuilder::middleware)
+//! and runs for every attempt, including retries. Layers see requests in the order they
+//! were added and responses in reverse order, so the first layer wraps all others.
+
+use std::{fmt, sync::Arc};
+
+use super::transport::{Request, Response, TransportError};
+
+/// A layer that can inspect or modify requests and responses, e.g. for logging, header
+/// mutation, auth rotation or metrics. Every hook defaults to doing nothing.
+pub trait Middleware: fmt::Debug + Send + Sync {
+    /// Called before `request` is sent.
+    fn on_request(&self, request: &mut Request) {
+        let _ = request;
+    }
+
+    /// Called when a response to `request` arrived, whatever its status.
+    fn on_response(&self, request: &Request, response: &mut Response) {
+        let _ = (request, response);
+    }
+
+    /// Called when no response to `request` could be received.
+    fn on_error(&self, request: &Request, error: &TransportError) {
+        let _ = (request, error);
+    }
+}
+
+/// Shares a layer, so its state (e.g. counters) stays reachable after building the client.
+impl<M: Middleware + ?Sized> Middleware for Arc<M> {
+    fn on_request(&self, request: &mut Request) {
+        (**self).on_request(request)
+    }
+
+    fn on_response(&self, request: &Request, response: &mut Response) {
+        (**self).on_response(request, response)
+    }
+
+    fn on_error(&self, request: &Request, error: &TransportError) {
+        (**self).on_error(request, error)
+    }
+}
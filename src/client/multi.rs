@@ -0,0 +1,200 @@
+//! Several clients, one per account, for running the same calls against all of them.
+
+use std::{borrow::Cow, collections::BTreeMap, future::Future, sync::Arc};
+
+use super::{rate_limiter::RateLimiter, Client, NewClientError};
+
+/// A value obtained through the client of the given account.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Tagged<T> {
+    pub account: String,
+    pub value: T,
+}
+
+/// An error returned by the call made for one account.
+#[derive(thiserror::Error, Debug)]
+#[error("account '{account}': {error}")]
+pub struct AccountError<E: std::error::Error + 'static> {
+    pub account: String,
+    #[source]
+    pub error: E,
+}
+
+/// Token-bearing clients keyed by account name, e.g. to fetch the transactions of every
+/// account run by a trader. Calls are made concurrently for all accounts.
+#[derive(Debug, Default)]
+pub struct MultiClient {
+    clients: BTreeMap<String, Client>,
+}
+
+impl MultiClient {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a client for every `(account, token)` pair. The clients share one rate
+    /// limiter, since the API rate-limits per IP rather than per key.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any client cannot be built.
+    pub fn from_tokens<I, A, T>(tokens: I) -> Result<Self, NewClientError>
+    where
+        I: IntoIterator<Item = (A, T)>,
+        A: Into<String>,
+        T: Into<Cow<'static, str>>,
+    {
+        let rate_limiter = Arc::new(RateLimiter::new(300, 5.0));
+        let mut multi = Self::new();
+
+        for (account, token) in tokens {
+            let client = Client::builder()
+                .token(token)
+                .rate_limiter(rate_limiter.clone())
+                .build()?;
+            multi.insert(account, client);
+        }
+
+        Ok(multi)
+    }
+
+    /// Adds the client of `account`, returning the client it replaces, if any.
+    pub fn insert(&mut self, account: impl Into<String>, client: Client) -> Option<Client> {
+        self.clients.insert(account.into(), client)
+    }
+
+    /// Removes the client of `account`.
+    pub fn remove(&mut self, account: &str) -> Option<Client> {
+        self.clients.remove(account)
+    }
+
+    /// The client of `account`.
+    pub fn get(&self, account: &str) -> Option<&Client> {
+        self.clients.get(account)
+    }
+
+    /// Account names, in alphabetical order.
+    pub fn accounts(&self) -> impl Iterator<Item = &str> {
+        self.clients.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.clients.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clients.is_empty()
+    }
+
+    /// Calls `f` with the client of every account concurrently, e.g.
+    /// `multi.fan_out(api::account::get_wallet)`. Results are in account order.
+    pub async fn fan_out<'a, F, Fut, T>(&'a self, f: F) -> Vec<Tagged<T>>
+    where
+        F: Fn(&'a Client) -> Fut,
+        Fut: Future<Output = T>,
+    {
+        let calls = self.clients.iter().map(|(account, client)| {
+            let call = f(client);
+            async move {
+                Tagged {
+                    account: account.clone(),
+                    value: call.await,
+                }
+            }
+        });
+
+        futures_util::future::join_all(calls).await
+    }
+
+    /// Calls `f` with the client of every account concurrently and merges the returned
+    /// lists, tagging each entry with its account, e.g.
+    /// `multi.fan_out_merged(api::transactions::get_current_sells)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of the first account (in account order) whose call failed.
+    pub async fn fan_out_merged<'a, F, Fut, T, E>(
+        &'a self,
+        f: F,
+    ) -> Result<Vec<Tagged<T>>, AccountError<E>>
+    where
+        F: Fn(&'a Client) -> Fut,
+        Fut: Future<Output = Result<Vec<T>, E>>,
+        E: std::error::Error + 'static,
+    {
+        let mut merged = Vec::new();
+
+        for result in self.fan_out(f).await {
+            let values = result.value.map_err(|error| AccountError {
+                account: result.account.clone(),
+                error,
+            })?;
+
+            merged.extend(values.into_iter().map(|value| Tagged {
+                account: result.account.clone(),
+                value,
+            }));
+        }
+
+        Ok(merged)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        api,
+        client::transport::{Request, Response, Transport, TransportFuture},
+    };
+
+    /// Answers with the ids of the requesting key's account.
+    #[derive(Debug)]
+    struct PerKeyTransport;
+
+    impl Transport for PerKeyTransport {
+        fn send(&self, request: Request) -> TransportFuture<'_> {
+            Box::pin(async move {
+                let auth = request.headers[reqwest::header::AUTHORIZATION]
+                    .to_str()
+                    .unwrap()
+                    .to_string();
+                let body = if auth.ends_with("first") {
+                    "[1, 2]"
+                } else {
+                    "[3]"
+                };
+
+                Ok(Response {
+                    status: reqwest::StatusCode::OK,
+                    headers: Default::default(),
+                    body: body.as_bytes().to_vec().into(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn fan_out_merges_tagged_results() {
+        let mut multi = MultiClient::new();
+        for (account, token) in [("b.5678", "second"), ("a.1234", "first")] {
+            let client = Client::builder()
+                .token(token)
+                .transport(PerKeyTransport)
+                .build()
+                .unwrap();
+            multi.insert(account, client);
+        }
+
+        let merged = multi
+            .fan_out_merged(api::account::get_skiffs)
+            .await
+            .unwrap();
+
+        let tagged: Vec<(&str, u32)> = merged
+            .iter()
+            .map(|entry| (entry.account.as_str(), entry.value))
+            .collect();
+        assert_eq!(tagged, [("a.1234", 1), ("a.1234", 2), ("b.5678", 3)]);
+    }
+}
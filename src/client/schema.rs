@@ -0,0 +1,338 @@
+//! Strict deserialization, reporting fields of API responses the models don't know about
+//! and model fields the API didn't send, to detect changes to the API's schema.
+
+use std::{cell::RefCell, collections::HashSet, fmt, sync::Mutex};
+
+use serde::{
+    de::{self, value::BorrowedStrDeserializer, DeserializeOwned},
+    forward_to_deserialize_any,
+};
+use serde_json::{Map, Value};
+
+use super::metrics::endpoint_path;
+
+/// How a response differs from the model it was deserialized into.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SchemaWarningKind {
+    /// The response has a field the model doesn't, so its value was dropped.
+    UnknownField,
+    /// The response lacks an optional field of the model, so it was defaulted.
+    MissingField,
+}
+
+/// A difference between a response and its model, see
+/// [`ClientBuilder::strict_schema`](super::ClientBuilder::strict_schema).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SchemaWarning {
+    /// The endpoint path, e.g. `/v2/items`.
+    pub endpoint: String,
+    /// The path of the field in the response, e.g. `[].details.type`. Array elements are
+    /// written as `[]`, so a field missing from every element is reported once.
+    pub field: String,
+    pub kind: SchemaWarningKind,
+}
+
+impl fmt::Display for SchemaWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.kind {
+            SchemaWarningKind::UnknownField => "unknown",
+            SchemaWarningKind::MissingField => "missing",
+        };
+        write!(f, "{} field `{}` in {}", kind, self.field, self.endpoint)
+    }
+}
+
+/// Collects the warnings of a client, reporting each one once.
+#[derive(Debug, Default)]
+pub(super) struct Recorder {
+    seen: Mutex<HashSet<SchemaWarning>>,
+    pending: Mutex<Vec<SchemaWarning>>,
+}
+
+impl Recorder {
+    /// Deserializes `body`, recording how it differs from `Response`.
+    pub(super) fn parse<Response: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &[u8],
+    ) -> Result<Response, serde_json::Error> {
+        let (response, fields) = from_slice(body)?;
+        if fields.is_empty() {
+            return Ok(response);
+        }
+
+        let endpoint = endpoint_path(url);
+        let mut seen = self.seen.lock().expect("schema warnings lock poisoned");
+        let mut pending = self.pending.lock().expect("schema warnings lock poisoned");
+        for (field, kind) in fields {
+            let warning = SchemaWarning {
+                endpoint: endpoint.clone(),
+                field,
+                kind,
+            };
+            if seen.insert(warning.clone()) {
+                tracing::warn!(%warning, "Response doesn't match its model");
+                pending.push(warning);
+            }
+        }
+
+        Ok(response)
+    }
+
+    pub(super) fn take(&self) -> Vec<SchemaWarning> {
+        std::mem::take(&mut *self.pending.lock().expect("schema warnings lock poisoned"))
+    }
+}
+
+type Fields = RefCell<Vec<(String, SchemaWarningKind)>>;
+
+/// Deserializes `body`, returning the path of every unknown and missing field.
+fn from_slice<T: DeserializeOwned>(
+    body: &[u8],
+) -> Result<(T, Vec<(String, SchemaWarningKind)>), serde_json::Error> {
+    let value: Value = serde_json::from_slice(body)?;
+    let fields = Fields::default();
+    let response = T::deserialize(Tracked {
+        value: &value,
+        path: String::new(),
+        fields: &fields,
+    })?;
+
+    Ok((response, fields.into_inner()))
+}
+
+/// Deserializes a JSON value like `&Value` does, tracking the fields of objects that are
+/// deserialized into structs. Enums are deserialized without tracking.
+struct Tracked<'de> {
+    value: &'de Value,
+    path: String,
+    fields: &'de Fields,
+}
+
+impl<'de> Tracked<'de> {
+    fn child(&self, value: &'de Value, name: &str) -> Self {
+        let path = if self.path.is_empty() {
+            name.to_string()
+        } else {
+            format!("{}.{}", self.path, name)
+        };
+
+        Self {
+            value,
+            path,
+            fields: self.fields,
+        }
+    }
+
+    fn object(
+        self,
+        object: &'de Map<String, Value>,
+        known: Option<&'static [&'static str]>,
+    ) -> TrackedMap<'de> {
+        TrackedMap {
+            entries: object.iter(),
+            current: None,
+            parent: self,
+            known,
+        }
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Tracked<'de> {
+    type Error = serde_json::Error;
+
+    fn deserialize_any<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Object(object) => visitor.visit_map(self.object(object, None)),
+            Value::Array(items) => visitor.visit_seq(TrackedSeq {
+                items: items.iter(),
+                parent: self,
+            }),
+            value => value.deserialize_any(visitor),
+        }
+    }
+
+    fn deserialize_option<V: de::Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+        match self.value {
+            Value::Null => visitor.visit_none(),
+            _ => visitor.visit_some(self),
+        }
+    }
+
+    fn deserialize_newtype_struct<V: de::Visitor<'de>>(
+        self,
+        _name: &'static str,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_newtype_struct(self)
+    }
+
+    fn deserialize_struct<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        fields: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        let Value::Object(object) = self.value else {
+            return self.value.deserialize_struct(name, fields, visitor);
+        };
+
+        let missing = fields.iter().filter(|field| !object.contains_key(**field));
+        for field in missing {
+            let path = self.child(self.value, field).path;
+            self.fields
+                .borrow_mut()
+                .push((path, SchemaWarningKind::MissingField));
+        }
+
+        visitor.visit_map(self.object(object, Some(fields)))
+    }
+
+    fn deserialize_enum<V: de::Visitor<'de>>(
+        self,
+        name: &'static str,
+        variants: &'static [&'static str],
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.value.deserialize_enum(name, variants, visitor)
+    }
+
+    fn deserialize_ignored_any<V: de::Visitor<'de>>(
+        self,
+        visitor: V,
+    ) -> Result<V::Value, Self::Error> {
+        visitor.visit_unit()
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf unit unit_struct seq tuple tuple_struct map identifier
+    }
+}
+
+struct TrackedMap<'de> {
+    entries: serde_json::map::Iter<'de>,
+    current: Option<(&'de String, &'de Value)>,
+    parent: Tracked<'de>,
+    /// The fields of the struct being deserialized, if any.
+    known: Option<&'static [&'static str]>,
+}
+
+impl<'de> de::MapAccess<'de> for TrackedMap<'de> {
+    type Error = serde_json::Error;
+
+    fn next_key_seed<K: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Self::Error> {
+        let Some((key, value)) = self.entries.next() else {
+            return Ok(None);
+        };
+        self.current = Some((key, value));
+
+        if self
+            .known
+            .is_some_and(|known| !known.contains(&key.as_str()))
+        {
+            let path = self.parent.child(value, key).path;
+            self.parent
+                .fields
+                .borrow_mut()
+                .push((path, SchemaWarningKind::UnknownField));
+        }
+
+        seed.deserialize(BorrowedStrDeserializer::new(key))
+            .map(Some)
+    }
+
+    fn next_value_seed<V: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: V,
+    ) -> Result<V::Value, Self::Error> {
+        let (key, value) = self
+            .current
+            .take()
+            .expect("next_value_seed called before next_key_seed");
+
+        seed.deserialize(self.parent.child(value, key))
+    }
+}
+
+struct TrackedSeq<'de> {
+    items: std::slice::Iter<'de, Value>,
+    parent: Tracked<'de>,
+}
+
+impl<'de> de::SeqAccess<'de> for TrackedSeq<'de> {
+    type Error = serde_json::Error;
+
+    fn next_element_seed<T: de::DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Self::Error> {
+        let Some(item) = self.items.next() else {
+            return Ok(None);
+        };
+
+        let path = format!("{}[]", self.parent.path);
+        seed.deserialize(Tracked {
+            value: item,
+            path,
+            fields: self.parent.fields,
+        })
+        .map(Some)
+    }
+
+    fn size_hint(&self) -> Option<usize> {
+        Some(self.items.len())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Item {
+        id: u32,
+        #[serde(default)]
+        flags: Vec<String>,
+        details: Option<Details>,
+    }
+
+    #[derive(serde::Deserialize, Debug, PartialEq)]
+    struct Details {
+        #[serde(rename = "type")]
+        kind: String,
+    }
+
+    #[test]
+    fn reports_unknown_and_missing_fields() {
+        let body = br#"[
+            { "id": 1, "flags": ["NoSell"], "details": { "type": "Axe", "damage": 3 } },
+            { "id": 2, "rarity": "Rare", "details": null },
+            { "id": 3, "rarity": "Basic" }
+        ]"#;
+
+        let (items, fields): (Vec<Item>, _) = from_slice(body).unwrap();
+        assert_eq!(items[0].details.as_ref().unwrap().kind, "Axe");
+        assert_eq!(items[2].flags, Vec::<String>::new());
+
+        let recorder = Recorder::default();
+        let _: Vec<Item> = recorder
+            .parse("https://api.guildwars2.com/v2/items?ids=1,2,3", body)
+            .unwrap();
+        let warnings: Vec<String> = recorder.take().iter().map(|w| w.to_string()).collect();
+        assert_eq!(
+            warnings,
+            vec![
+                "unknown field `[].details.damage` in /v2/items",
+                "missing field `[].flags` in /v2/items",
+                "unknown field `[].rarity` in /v2/items",
+                "missing field `[].details` in /v2/items",
+            ]
+        );
+        assert_eq!(fields.len(), 6);
+        assert!(recorder.take().is_empty());
+    }
+}
@@ -0,0 +1,115 @@
+//! The HTTP layer used by [`Client`](super::Client).
+//!
+//! Requests go through a [`Transport`], which defaults to [`ReqwestTransport`]. Tests and
+//! downstream crates can implement the trait to serve canned responses without a network,
+//! and every function in [`crate::api`] works unchanged against it.
+
+use std::fmt;
+
+use bytes::Bytes;
+use reqwest::{header::HeaderMap, StatusCode};
+
+type BoxError = Box<dyn std::error::Error + Send + Sync>;
+
+/// The future returned by [`Transport::send`]. Not `Send` on wasm, where the browser's
+/// fetch API can't be used from other threads.
+#[cfg(not(target_arch = "wasm32"))]
+pub type TransportFuture<'a> =
+    futures_util::future::BoxFuture<'a, Result<Response, TransportError>>;
+#[cfg(target_arch = "wasm32")]
+pub type TransportFuture<'a> =
+    futures_util::future::LocalBoxFuture<'a, Result<Response, TransportError>>;
+
+/// A GET request to send.
+#[derive(Debug, Clone)]
+pub struct Request {
+    pub url: String,
+    /// Headers to send, including authorization and user agent.
+    pub headers: HeaderMap,
+}
+
+/// A response with its body fully read, whatever its status.
+#[derive(Debug, Clone)]
+pub struct Response {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+/// Failure to get any response from the API.
+#[derive(thiserror::Error, Debug)]
+pub enum TransportError {
+    #[error(transparent)]
+    Reqwest(#[from] reqwest::Error),
+    #[error("request timed out: {0}")]
+    Timeout(BoxError),
+    #[error("connection failed: {0}")]
+    Connect(BoxError),
+    #[error(transparent)]
+    Other(BoxError),
+}
+
+impl TransportError {
+    /// Whether the request timed out.
+    pub fn is_timeout(&self) -> bool {
+        match self {
+            TransportError::Reqwest(e) => e.is_timeout(),
+            TransportError::Timeout(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Whether the connection could not be established or was interrupted.
+    pub fn is_connect(&self) -> bool {
+        match self {
+            #[cfg(not(target_arch = "wasm32"))]
+            TransportError::Reqwest(e) => e.is_connect() || e.is_request(),
+            #[cfg(target_arch = "wasm32")]
+            TransportError::Reqwest(e) => e.is_request(),
+            TransportError::Connect(_) => true,
+            _ => false,
+        }
+    }
+}
+
+/// Sends requests to the API.
+pub trait Transport: fmt::Debug + Send + Sync {
+    /// Sends `request` and reads the full response. Non-success statuses are returned as
+    /// responses, not errors.
+    fn send(&self, request: Request) -> TransportFuture<'_>;
+}
+
+/// The default transport, backed by a `reqwest::Client`.
+#[derive(Debug, Clone, Default)]
+pub struct ReqwestTransport {
+    client: reqwest::Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: reqwest::Client) -> Self {
+        Self { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let response = self
+                .client
+                .get(request.url)
+                .headers(request.headers)
+                .send()
+                .await?;
+
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
+
+            Ok(Response {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
@@ -0,0 +1,181 @@
+//! Amounts of coins, the game's currency: 100 copper make a silver, and 100 silver a gold.
+
+use std::{
+    fmt,
+    iter::Sum,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+use rust_decimal::{prelude::ToPrimitive, Decimal, RoundingStrategy};
+
+/// An amount of coins in copper. Negative amounts are losses, e.g. of a trade.
+#[derive(
+    serde::Deserialize,
+    serde::Serialize,
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+)]
+#[serde(transparent)]
+pub struct Coin(pub i64);
+
+/// Error converting a [`Decimal`] too large for [`Coin`].
+#[derive(thiserror::Error, Debug)]
+#[error("{0} is out of range for coins")]
+pub struct CoinRangeError(pub Decimal);
+
+impl Coin {
+    pub const ZERO: Self = Self(0);
+
+    /// An amount of gold, silver and copper, e.g. `Coin::new(1, 50, 0)` for 1g 50s.
+    pub const fn new(gold: i64, silver: i64, copper: i64) -> Self {
+        Self(gold * 10_000 + silver * 100 + copper)
+    }
+
+    pub const fn from_copper(copper: i64) -> Self {
+        Self(copper)
+    }
+
+    pub const fn copper(self) -> i64 {
+        self.0
+    }
+
+    /// The gold part of the amount, e.g. 1 for 1g 50s.
+    pub const fn gold(self) -> i64 {
+        self.0 / 10_000
+    }
+
+    /// The silver part of the amount, e.g. 50 for 1g 50s.
+    pub const fn silver(self) -> i64 {
+        self.0 / 100 % 100
+    }
+
+    /// The copper part of the amount, e.g. 0 for 1g 50s.
+    pub const fn copper_part(self) -> i64 {
+        self.0 % 100
+    }
+}
+
+impl fmt::Display for Coin {
+    /// Formats as e.g. `1g 5s 20c`, leaving out leading zero units (`5s 20c`, `20c`).
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0 < 0 {
+            write!(f, "-")?;
+        }
+
+        let copper = self.0.unsigned_abs();
+        let (gold, silver, copper) = (copper / 10_000, copper / 100 % 100, copper % 100);
+        if gold > 0 {
+            write!(f, "{}g {}s {}c", gold, silver, copper)
+        } else if silver > 0 {
+            write!(f, "{}s {}c", silver, copper)
+        } else {
+            write!(f, "{}c", copper)
+        }
+    }
+}
+
+impl From<u32> for Coin {
+    fn from(copper: u32) -> Self {
+        Self(copper.into())
+    }
+}
+
+impl From<Coin> for Decimal {
+    fn from(coin: Coin) -> Self {
+        coin.0.into()
+    }
+}
+
+/// Rounds to the nearest copper.
+impl TryFrom<Decimal> for Coin {
+    type Error = CoinRangeError;
+
+    fn try_from(value: Decimal) -> Result<Self, Self::Error> {
+        value
+            .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+            .to_i64()
+            .map(Self)
+            .ok_or(CoinRangeError(value))
+    }
+}
+
+impl Add for Coin {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl AddAssign for Coin {
+    fn add_assign(&mut self, rhs: Self) {
+        self.0 += rhs.0;
+    }
+}
+
+impl Sub for Coin {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+impl SubAssign for Coin {
+    fn sub_assign(&mut self, rhs: Self) {
+        self.0 -= rhs.0;
+    }
+}
+
+impl Neg for Coin {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self(-self.0)
+    }
+}
+
+/// The price of a quantity of items.
+impl Mul<u32> for Coin {
+    type Output = Self;
+
+    fn mul(self, quantity: u32) -> Self {
+        Self(self.0 * i64::from(quantity))
+    }
+}
+
+impl Sum for Coin {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, Add::add)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn formats_gold_silver_copper() {
+        assert_eq!(Coin::new(12, 5, 3).to_string(), "12g 5s 3c");
+        assert_eq!(Coin::new(1, 0, 0).to_string(), "1g 0s 0c");
+        assert_eq!(Coin(520).to_string(), "5s 20c");
+        assert_eq!(Coin(7).to_string(), "7c");
+        assert_eq!(Coin(-10_520).to_string(), "-1g 5s 20c");
+    }
+
+    #[test]
+    fn converts_decimals_to_nearest_copper() {
+        assert_eq!(Coin::try_from(dec!(12.5)).unwrap(), Coin(13));
+        assert_eq!(Coin::try_from(dec!(-3.4)).unwrap(), Coin(-3));
+        assert_eq!(Decimal::from(Coin(150) * 3 - Coin(50)), dec!(400));
+        assert!(Coin::try_from(Decimal::MAX).is_err());
+    }
+}
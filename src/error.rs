@@ -0,0 +1,249 @@
+//! A single error type that every error of this crate converts into, for callers composing
+//! several endpoint families.
+
+use crate::{
+    api::tokeninfo::Permission,
+    api::{self, listings::GetManyListingsError, prices::GetManyPricesError},
+    client::{
+        multi::AccountError, ApiError, GetError, NewClientError, PaginatedGetError,
+        PartialResultError, TransportError, VerifyPermissionsError,
+    },
+    coin::CoinRangeError,
+    strategy::{scanner::ScanError, undercut::UndercutError},
+};
+
+/// Any error returned by this crate.
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("failed to create client: {0}")]
+    NewClient(#[from] NewClientError),
+
+    #[error("HTTP request error: {0}")]
+    Transport(#[from] TransportError),
+
+    #[error("Request failed: status {status}, url: {url}: {error}")]
+    Api {
+        status: reqwest::StatusCode,
+        url: String,
+        error: ApiError,
+    },
+
+    #[error("Failed to deserialize response body: {0}")]
+    Deserialization(#[from] serde_json::Error),
+
+    /// Pagination headers were missing or invalid.
+    #[error("{0}")]
+    Pagination(PaginatedGetError),
+
+    #[error("max of 200 ids are allowed, got {0}")]
+    TooManyIds(usize),
+
+    #[error("invalid API key: {0}")]
+    InvalidToken(reqwest::header::InvalidHeaderValue),
+
+    #[error("no API key configured")]
+    MissingToken,
+
+    #[error("API key is missing permissions: {}", .0.iter().map(|p| p.name()).collect::<Vec<_>>().join(", "))]
+    MissingPermissions(Vec<Permission>),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("{0}")]
+    CoinRange(#[from] CoinRangeError),
+
+    /// A call made for one account of a [`MultiClient`](crate::client::multi::MultiClient)
+    /// failed.
+    #[error("account '{account}': {source}")]
+    Account { account: String, source: Box<Error> },
+}
+
+impl Error {
+    /// Whether the request may succeed if tried again later: timeouts, connection failures,
+    /// rate limiting and server errors.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Error::Transport(e) => e.is_timeout() || e.is_connect(),
+            Error::Api { status, error, .. } => {
+                status.is_server_error()
+                    || matches!(error, ApiError::RateLimited(_) | ApiError::Maintenance(_))
+            }
+            Error::Account { source, .. } => source.is_retryable(),
+            _ => false,
+        }
+    }
+
+    /// Whether the API key is missing, invalid or lacks a required permission.
+    pub fn is_auth(&self) -> bool {
+        match self {
+            Error::MissingToken | Error::MissingPermissions(_) => true,
+            Error::Api { status, error, .. } => {
+                matches!(error, ApiError::InvalidKey(_) | ApiError::MissingScope(_))
+                    || *status == reqwest::StatusCode::UNAUTHORIZED
+                    || *status == reqwest::StatusCode::FORBIDDEN
+            }
+            Error::Account { source, .. } => source.is_auth(),
+            _ => false,
+        }
+    }
+
+    /// The error reported by the API, if it answered with an error status.
+    pub fn api_error(&self) -> Option<&ApiError> {
+        match self {
+            Error::Api { error, .. } => Some(error),
+            Error::Account { source, .. } => source.api_error(),
+            _ => None,
+        }
+    }
+}
+
+impl From<GetError> for Error {
+    fn from(err: GetError) -> Self {
+        match err {
+            GetError::Http(e) => Error::Transport(e),
+            GetError::Api { status, url, error } => Error::Api { status, url, error },
+            GetError::DeserializationError(e) => Error::Deserialization(e),
+            GetError::InvalidToken(e) => Error::InvalidToken(e),
+        }
+    }
+}
+
+impl From<PaginatedGetError> for Error {
+    fn from(err: PaginatedGetError) -> Self {
+        match err {
+            PaginatedGetError::Http(e) => Error::Transport(e),
+            PaginatedGetError::Api { status, url, error } => Error::Api { status, url, error },
+            PaginatedGetError::DeserializationError(e) => Error::Deserialization(e),
+            PaginatedGetError::Auth(e) => (*e).into(),
+            err => Error::Pagination(err),
+        }
+    }
+}
+
+/// Drops the items fetched before the failure.
+impl<Item> From<PartialResultError<Item>> for Error {
+    fn from(err: PartialResultError<Item>) -> Self {
+        err.error.into()
+    }
+}
+
+impl From<api::GetManyError> for Error {
+    fn from(err: api::GetManyError) -> Self {
+        match err {
+            api::GetManyError::TooManyIds(count) => Error::TooManyIds(count),
+            api::GetManyError::ClientError(e) => e.into(),
+        }
+    }
+}
+
+impl From<GetManyListingsError> for Error {
+    fn from(err: GetManyListingsError) -> Self {
+        match err {
+            GetManyListingsError::TooManyListingIds(count) => Error::TooManyIds(count),
+            GetManyListingsError::ClientError(e) => e.into(),
+        }
+    }
+}
+
+impl From<GetManyPricesError> for Error {
+    fn from(err: GetManyPricesError) -> Self {
+        match err {
+            GetManyPricesError::TooManyItemIds(count) => Error::TooManyIds(count),
+            GetManyPricesError::ClientError(e) => e.into(),
+        }
+    }
+}
+
+impl From<VerifyPermissionsError> for Error {
+    fn from(err: VerifyPermissionsError) -> Self {
+        match err {
+            VerifyPermissionsError::MissingToken => Error::MissingToken,
+            VerifyPermissionsError::MissingPermissions(missing) => {
+                Error::MissingPermissions(missing)
+            }
+            VerifyPermissionsError::ClientError(e) => e.into(),
+        }
+    }
+}
+
+impl<E> From<AccountError<E>> for Error
+where
+    E: std::error::Error + Into<Error> + 'static,
+{
+    fn from(err: AccountError<E>) -> Self {
+        Error::Account {
+            account: err.account,
+            source: Box::new(err.error.into()),
+        }
+    }
+}
+
+impl From<ScanError> for Error {
+    fn from(err: ScanError) -> Self {
+        match err {
+            ScanError::Prices(e) => e.into(),
+            ScanError::VendorValues(e) => e.into(),
+        }
+    }
+}
+
+impl From<UndercutError> for Error {
+    fn from(err: UndercutError) -> Self {
+        match err {
+            UndercutError::Transactions(e) => e.into(),
+            UndercutError::Listings(e) => e.into(),
+        }
+    }
+}
+
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+impl From<crate::blocking::NewBlockingClientError> for Error {
+    fn from(err: crate::blocking::NewBlockingClientError) -> Self {
+        match err {
+            crate::blocking::NewBlockingClientError::Client(e) => e.into(),
+            crate::blocking::NewBlockingClientError::Runtime(e) => e.into(),
+        }
+    }
+}
+
+/// Result type defaulting to this crate's [`Error`].
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_api_errors() {
+        let error: Error = GetError::Api {
+            status: reqwest::StatusCode::FORBIDDEN,
+            url: "https://api.guildwars2.com/v2/commerce/transactions".into(),
+            error: ApiError::MissingScope("tradingpost".into()),
+        }
+        .into();
+        assert!(error.is_auth());
+        assert!(!error.is_retryable());
+
+        let error: Error = PaginatedGetError::Api {
+            status: reqwest::StatusCode::SERVICE_UNAVAILABLE,
+            url: "https://api.guildwars2.com/v2/commerce/prices".into(),
+            error: ApiError::Maintenance("API not active".into()),
+        }
+        .into();
+        assert!(error.is_retryable());
+        assert!(!error.is_auth());
+
+        let error: Error = AccountError {
+            account: "trader.1234".into(),
+            error: UndercutError::Listings(GetError::Api {
+                status: reqwest::StatusCode::UNAUTHORIZED,
+                url: "https://api.guildwars2.com/v2/commerce/listings".into(),
+                error: ApiError::InvalidKey("Invalid access token".into()),
+            }),
+        }
+        .into();
+        assert!(error.is_auth());
+        assert!(matches!(error.api_error(), Some(ApiError::InvalidKey(_))));
+    }
+}
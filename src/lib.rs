@@ -1,3 +1,11 @@
 pub mod api;
+#[cfg(all(feature = "blocking", not(target_arch = "wasm32")))]
+pub mod blocking;
 pub mod client;
+pub mod coin;
+pub mod error;
+mod runtime;
 pub mod strategy;
+
+pub use coin::Coin;
+pub use error::Error;
@@ -1,6 +1,6 @@
 use gw2gd::{api, client::Client};
 
-#[tokio::main]
+#[tokio::main(flavor = "current_thread")]
 async fn main() -> eyre::Result<()> {
     tracing_subscriber::fmt()
         .with_max_level(tracing::Level::TRACE)
@@ -0,0 +1,73 @@
+//! Timers used by the client: tokio's with the `tokio-runtime` feature, a runtime-agnostic
+//! timer thread without it, and the host's `setTimeout` on wasm, where tokio's timers and
+//! `std::time::Instant` are unavailable.
+
+use std::{future::Future, time::Duration};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::{Instant, SystemTime};
+#[cfg(target_arch = "wasm32")]
+pub use web_time::{Instant, SystemTime};
+
+/// Waits until `duration` has elapsed.
+pub async fn sleep(duration: Duration) {
+    #[cfg(all(not(target_arch = "wasm32"), feature = "tokio-runtime"))]
+    tokio::time::sleep(duration).await;
+
+    #[cfg(all(not(target_arch = "wasm32"), not(feature = "tokio-runtime")))]
+    futures_timer::Delay::new(duration).await;
+
+    #[cfg(target_arch = "wasm32")]
+    wasm::sleep(duration).await;
+}
+
+/// Returned by [`timeout`] when the future didn't complete in time.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Elapsed;
+
+/// Runs `future`, giving up once `duration` has elapsed.
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> Result<F::Output, Elapsed> {
+    #[cfg(all(not(target_arch = "wasm32"), feature = "tokio-runtime"))]
+    {
+        tokio::time::timeout(duration, future)
+            .await
+            .map_err(|_| Elapsed)
+    }
+
+    #[cfg(any(target_arch = "wasm32", not(feature = "tokio-runtime")))]
+    {
+        use futures_util::future::{select, Either};
+
+        let future = std::pin::pin!(future);
+        let sleep = std::pin::pin!(sleep(duration));
+
+        match select(future, sleep).await {
+            Either::Left((output, _)) => Ok(output),
+            Either::Right(_) => Err(Elapsed),
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod wasm {
+    use std::time::Duration;
+
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        // Available in browsers, workers and node alike.
+        #[wasm_bindgen(js_name = setTimeout)]
+        fn set_timeout(handler: &js_sys::Function, timeout: i32) -> JsValue;
+    }
+
+    pub async fn sleep(duration: Duration) {
+        let millis = duration.as_millis().min(i32::MAX as u128) as i32;
+        let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+            set_timeout(&resolve, millis);
+        });
+
+        // The promise never rejects
+        let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+    }
+}
@@ -1,19 +1,147 @@
 use std::collections::BTreeMap;
 
-use rust_decimal::Decimal;
+use rust_decimal::{Decimal, RoundingStrategy};
 use rust_decimal_macros::dec;
 
+use crate::{
+    api::{
+        items,
+        listings::{ListingItem, Listings},
+        ItemId,
+    },
+    client::{Client, GetError},
+    coin::Coin,
+};
+
+pub mod allocator;
+pub mod crafting;
+pub mod gems;
+pub mod indicators;
+pub mod metrics;
+pub mod scanner;
+pub mod undercut;
+
 pub type Price = Decimal;
 pub type Size = Decimal;
-pub type Profit = Decimal;
+pub type Profit = Coin;
 
 pub struct Id(pub usize);
 
-pub const SELL_FEE: Decimal = dec!(0.15);
+/// The fees the trading post charges for selling an item, as fractions of its price.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeSchedule {
+    /// Charged up front when listing, and not refunded if the listing is cancelled.
+    pub listing_fee: Decimal,
+    /// Charged once the item sells.
+    pub exchange_fee: Decimal,
+    /// The least each fee amounts to, in copper.
+    pub minimum_fee: Price,
+}
+
+impl FeeSchedule {
+    /// The trading post's fees: 5% listing fee and 10% exchange fee, at least 1 copper each.
+    pub const TRADING_POST: Self = Self {
+        listing_fee: dec!(0.05),
+        exchange_fee: dec!(0.10),
+        minimum_fee: dec!(1),
+    };
+
+    fn fee(&self, rate: Decimal, price: Price) -> Price {
+        (price * rate)
+            .round_dp_with_strategy(0, RoundingStrategy::MidpointAwayFromZero)
+            .max(self.minimum_fee)
+    }
+
+    /// The fee for listing an item at `price`, rounded to the nearest copper like in game.
+    pub fn listing_fee(&self, price: Price) -> Price {
+        self.fee(self.listing_fee, price)
+    }
+
+    /// The fee for an item selling at `price`, rounded to the nearest copper like in game.
+    pub fn exchange_fee(&self, price: Price) -> Price {
+        self.fee(self.exchange_fee, price)
+    }
+
+    /// Both fees of selling an item at `price`.
+    pub fn total_fee(&self, price: Price) -> Price {
+        self.listing_fee(price) + self.exchange_fee(price)
+    }
+
+    /// What the seller keeps of an item sold at `price`.
+    pub fn net_proceeds(&self, price: Price) -> Price {
+        price - self.total_fee(price)
+    }
+
+    /// The lowest whole-copper price an item bought at `buy_price` can be sold at without a
+    /// loss. `None` if the fees take everything.
+    pub fn break_even_price(&self, buy_price: Price) -> Option<Price> {
+        self.min_price_netting(buy_price.ceil())
+    }
+
+    /// The lowest whole-copper price an item bought at `buy_price` has to be sold at to make
+    /// a return of `margin` after fees, e.g. 0.1 for 10%. `None` if the fees take
+    /// everything.
+    pub fn sell_price_for_margin(&self, buy_price: Price, margin: Decimal) -> Option<Price> {
+        self.min_price_netting((buy_price * (Decimal::ONE + margin)).ceil())
+    }
+
+    /// The lowest whole-copper price whose net proceeds are at least `target`.
+    fn min_price_netting(&self, target: Price) -> Option<Price> {
+        let kept = Decimal::ONE - self.listing_fee - self.exchange_fee;
+        if kept <= Decimal::ZERO {
+            return None;
+        }
+
+        // Rounding lowers each fee by at most half a copper, so no price below this nets
+        // `target`. Minimum fees only lower the proceeds further.
+        let mut price = ((target - Decimal::ONE) / kept).floor().max(Decimal::ZERO);
+        while self.net_proceeds(price) < target {
+            price += Decimal::ONE;
+        }
+
+        Some(price)
+    }
+}
+
+impl Default for FeeSchedule {
+    fn default() -> Self {
+        Self::TRADING_POST
+    }
+}
 
 pub struct Market {
     pub id: Id,
     pub orderbook: Orderbook,
+    /// What a merchant pays for the item, if merchants buy it. Selling on the trading post
+    /// for less is never worth it.
+    pub vendor_value: Option<Price>,
+}
+
+impl Market {
+    /// Sets what a merchant pays for the item, e.g. from
+    /// [`Item::vendor_price`](crate::api::items::Item::vendor_price).
+    pub fn with_vendor_value(mut self, vendor_value: Price) -> Self {
+        self.vendor_value = Some(vendor_value);
+        self
+    }
+
+    /// Sell offers below the vendor value. Buying them to sell to a merchant is a profit.
+    pub fn asks_below_vendor_value(&self) -> impl Iterator<Item = &Level> {
+        let vendor_value = self.vendor_value.unwrap_or(Decimal::ZERO);
+        self.orderbook
+            .asks()
+            .take_while(move |level| level.price < vendor_value)
+    }
+
+    /// What selling an item listed at `price` yields after fees, or selling it to a merchant
+    /// if that pays more.
+    pub fn sell_proceeds(&self, price: Price, fees: &FeeSchedule) -> Price {
+        let proceeds = fees.net_proceeds(price);
+        match self.vendor_value {
+            Some(vendor_value) if vendor_value > proceeds => vendor_value,
+            _ => proceeds,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -48,41 +176,183 @@ impl Orderbook {
     }
 }
 
-/// Determines profit from spread
-pub fn calc_profit_from_spread(ob: &Orderbook) -> Option<Profit> {
+impl From<&ListingItem> for Level {
+    fn from(listing: &ListingItem) -> Self {
+        Self {
+            price: listing.unit_price.into(),
+            size: listing.quantity.into(),
+        }
+    }
+}
+
+/// Buy orders become bids and sell offers asks.
+impl From<&Listings> for Orderbook {
+    fn from(listings: &Listings) -> Self {
+        Self::new(
+            listings.buys.iter().map(Level::from),
+            listings.sells.iter().map(Level::from),
+        )
+    }
+}
+
+impl From<Listings> for Market {
+    fn from(listings: Listings) -> Self {
+        Self {
+            id: Id(listings.id.0 as usize),
+            orderbook: Orderbook::from(&listings),
+            vendor_value: None,
+        }
+    }
+}
+
+/// Determines profit from spread: buying at the best bid and selling at the best ask,
+/// after `fees`.
+pub fn calc_profit_from_spread(ob: &Orderbook, fees: &FeeSchedule) -> Option<Profit> {
     let best_ask = ob.asks().next()?;
     let best_bid = ob.bids().next()?;
-    let gross_profit = best_ask.price - best_bid.price;
-    Some(gross_profit - (best_ask.price * SELL_FEE))
+    Coin::try_from(fees.net_proceeds(best_ask.price) - best_bid.price).ok()
+}
+
+/// Determines the profit of buying at the best bid and selling at the best ask after
+/// `fees`, or to a merchant if the market's vendor value pays more.
+pub fn calc_market_profit(market: &Market, fees: &FeeSchedule) -> Option<Profit> {
+    let best_ask = market.orderbook.asks().next()?;
+    let best_bid = market.orderbook.bids().next()?;
+    Coin::try_from(market.sell_proceeds(best_ask.price, fees) - best_bid.price).ok()
 }
 
+/// The profit of flipping a market, along with how much capital it ties up.
+#[derive(Clone, Copy)]
+pub struct MarketProfit<'a> {
+    pub market: &'a Market,
+    /// Profit per item after fees.
+    pub profit: Profit,
+    /// Whether selling to a merchant pays more than relisting on the trading post.
+    pub sell_to_vendor: bool,
+    /// Profit relative to the buy price, e.g. 0.25 for 25%.
+    pub roi: Decimal,
+    /// Profit relative to all capital tied up until the item sells: the buy price and the
+    /// listing fee paid up front.
+    pub profit_per_capital: Decimal,
+}
+
+impl<'a> MarketProfit<'a> {
+    fn new(market: &'a Market, fees: &FeeSchedule) -> Option<Self> {
+        let profit = calc_market_profit(market, fees)?;
+        let buy_price = market.orderbook.bids().next()?.price;
+        let sell_price = market.orderbook.asks().next()?.price;
+        if buy_price <= Decimal::ZERO {
+            return None;
+        }
+
+        let sell_to_vendor = market.sell_proceeds(sell_price, fees) > fees.net_proceeds(sell_price);
+        // Merchants don't charge a listing fee
+        let listing_fee = if sell_to_vendor {
+            Decimal::ZERO
+        } else {
+            fees.listing_fee(sell_price)
+        };
+
+        let gain = Decimal::from(profit);
+        Some(Self {
+            market,
+            profit,
+            sell_to_vendor,
+            roi: gain / buy_price,
+            profit_per_capital: gain / (buy_price + listing_fee),
+        })
+    }
+}
+
+/// Markets ranked by how profitable flipping them is. Markets without a positive bid are
+/// left out, as their return can't be determined.
 pub struct ProfitResult<'a> {
-    inner: BTreeMap<Profit, &'a Market>,
+    /// Sorted by profit, highest first.
+    inner: Vec<MarketProfit<'a>>,
 }
 
 impl<'a> ProfitResult<'a> {
+    /// Markets by absolute profit per item, highest first.
     pub fn iter(&self) -> impl Iterator<Item = (&Profit, &&Market)> {
-        self.inner.iter().rev()
+        self.inner
+            .iter()
+            .map(|entry| (&entry.profit, &entry.market))
     }
 
     pub fn best(&self) -> Option<(&Profit, &&Market)> {
         self.iter().next()
     }
+
+    /// Markets by return on investment, highest first. Unlike absolute profit, this doesn't
+    /// favor expensive items.
+    pub fn iter_by_roi(&self) -> impl Iterator<Item = &MarketProfit<'a>> {
+        self.sorted_by(|entry| entry.roi).into_iter()
+    }
+
+    pub fn best_by_roi(&self) -> Option<&MarketProfit<'a>> {
+        self.inner.iter().max_by_key(|entry| entry.roi)
+    }
+
+    /// Markets by profit per unit of capital tied up, highest first.
+    pub fn iter_by_profit_per_capital(&self) -> impl Iterator<Item = &MarketProfit<'a>> {
+        self.sorted_by(|entry| entry.profit_per_capital).into_iter()
+    }
+
+    pub fn best_by_profit_per_capital(&self) -> Option<&MarketProfit<'a>> {
+        self.inner
+            .iter()
+            .max_by_key(|entry| entry.profit_per_capital)
+    }
+
+    fn sorted_by(&self, key: impl Fn(&MarketProfit<'a>) -> Decimal) -> Vec<&MarketProfit<'a>> {
+        let mut sorted: Vec<_> = self.inner.iter().collect();
+        sorted.sort_by_key(|entry| std::cmp::Reverse(key(entry)));
+        sorted
+    }
+}
+
+/// Fetches the items of `markets` and sets their vendor values.
+/// Corresponds to GET /v2/items?ids=...
+pub async fn fetch_vendor_values(client: &Client, markets: &mut [Market]) -> Result<(), GetError> {
+    let ids: Vec<ItemId> = markets
+        .iter()
+        .map(|market| ItemId(market.id.0 as u32))
+        .collect();
+    let vendor_values = get_vendor_values(client, &ids).await?;
+
+    for market in markets {
+        market.vendor_value = vendor_values
+            .get(&ItemId(market.id.0 as u32))
+            .map(|value| (*value).into());
+    }
+    Ok(())
 }
 
-pub fn find_profit<'a, Markets>(obs: Markets) -> ProfitResult<'a>
+/// Fetches what a merchant pays for each of `ids`, leaving out items merchants don't buy.
+/// Corresponds to GET /v2/items?ids=...
+pub async fn get_vendor_values(
+    client: &Client,
+    ids: &[ItemId],
+) -> Result<std::collections::HashMap<ItemId, Coin>, GetError> {
+    Ok(items::get_items_chunked(client, ids)
+        .await?
+        .found
+        .iter()
+        .filter_map(|item| Some((item.id, item.vendor_price()?)))
+        .collect())
+}
+
+pub fn find_profit<'a, Markets>(obs: Markets, fees: &FeeSchedule) -> ProfitResult<'a>
 where
     Markets: IntoIterator<Item = &'a Market>,
 {
-    ProfitResult {
-        inner: obs
-            .into_iter()
-            .filter_map(|market| {
-                let profit = calc_profit_from_spread(&market.orderbook)?;
-                Some((profit, market))
-            })
-            .collect(),
-    }
+    let mut inner: Vec<_> = obs
+        .into_iter()
+        .filter_map(|market| MarketProfit::new(market, fees))
+        .collect();
+    inner.sort_by_key(|entry| std::cmp::Reverse(entry.profit));
+
+    ProfitResult { inner }
 }
 
 #[cfg(test)]
@@ -114,8 +384,75 @@ mod tests {
             ],
         );
 
-        let profit = calc_profit_from_spread(&ob).unwrap();
-        assert_eq!(profit, dec!(1) - (dec!(3) * SELL_FEE));
+        // Both fees are at least 1 copper on cheap items
+        let profit = calc_profit_from_spread(&ob, &FeeSchedule::default()).unwrap();
+        assert_eq!(profit, Coin(-1));
+    }
+
+    #[test]
+    fn fees_round_to_copper() {
+        let fees = FeeSchedule::TRADING_POST;
+        assert_eq!(fees.listing_fee(dec!(1)), dec!(1));
+        assert_eq!(fees.exchange_fee(dec!(5)), dec!(1));
+        assert_eq!(fees.listing_fee(dec!(30)), dec!(2));
+        assert_eq!(fees.exchange_fee(dec!(34)), dec!(3));
+        assert_eq!(fees.total_fee(dec!(10000)), dec!(1500));
+        assert_eq!(fees.net_proceeds(dec!(10000)), dec!(8500));
+    }
+
+    #[test]
+    fn break_even_covers_fees() {
+        let fees = FeeSchedule::TRADING_POST;
+        for buy in [1, 2, 7, 30, 99, 100, 1234, 99_999] {
+            let buy = Decimal::from(buy);
+            let sell = fees.break_even_price(buy).unwrap();
+            assert!(fees.net_proceeds(sell) >= buy);
+            assert!(fees.net_proceeds(sell - dec!(1)) < buy);
+        }
+
+        assert_eq!(fees.break_even_price(dec!(1)), Some(dec!(3)));
+        assert_eq!(fees.break_even_price(dec!(8500)), Some(dec!(10000)));
+        assert_eq!(
+            fees.sell_price_for_margin(dec!(8500), dec!(0.1)),
+            Some(dec!(11000))
+        );
+
+        let greedy = FeeSchedule {
+            exchange_fee: dec!(0.95),
+            ..FeeSchedule::TRADING_POST
+        };
+        assert_eq!(greedy.break_even_price(dec!(100)), None);
+    }
+
+    #[test]
+    fn market_from_listings() {
+        let listings: Listings = serde_json::from_value(serde_json::json!({
+            "id": 19721,
+            "buys": [
+                { "listings": 1, "unit_price": 100, "quantity": 250 },
+                { "listings": 3, "unit_price": 99, "quantity": 500 }
+            ],
+            "sells": [{ "listings": 2, "unit_price": 120, "quantity": 80 }]
+        }))
+        .unwrap();
+
+        let market = Market::from(listings);
+        assert_eq!(market.id.0, 19721);
+        assert_eq!(
+            market.orderbook.bids().next(),
+            Some(&Level {
+                price: dec!(100),
+                size: dec!(250),
+            })
+        );
+        assert_eq!(market.orderbook.bids().count(), 2);
+        assert_eq!(
+            market.orderbook.asks().next(),
+            Some(&Level {
+                price: dec!(120),
+                size: dec!(80),
+            })
+        );
     }
 
     #[test]
@@ -133,6 +470,7 @@ mod tests {
                     }],
                 ),
                 id: Id(1),
+                vendor_value: None,
             },
             Market {
                 orderbook: Orderbook::new(
@@ -146,6 +484,7 @@ mod tests {
                     }],
                 ),
                 id: Id(2),
+                vendor_value: None,
             },
             Market {
                 orderbook: Orderbook::new(
@@ -159,11 +498,93 @@ mod tests {
                     }],
                 ),
                 id: Id(3),
+                vendor_value: None,
             },
         ];
-        let result = find_profit(&obs);
+        let result = find_profit(&obs, &FeeSchedule::default());
         let best = result.best().unwrap();
-        assert_eq!(*best.0, dec!(3) - (dec!(5) * SELL_FEE));
+        assert_eq!(*best.0, Coin(1));
         assert_eq!(result.iter().count(), 3);
     }
+
+    #[test]
+    fn rank_by_roi() {
+        let market = |id, bid, ask| Market {
+            orderbook: Orderbook::new(
+                [Level {
+                    price: bid,
+                    size: dec!(1),
+                }],
+                [Level {
+                    price: ask,
+                    size: dec!(1),
+                }],
+            ),
+            id: Id(id),
+            vendor_value: None,
+        };
+        let obs = [
+            market(1, dec!(100000), dec!(130000)),
+            market(2, dec!(100), dec!(200)),
+        ];
+
+        let result = find_profit(&obs, &FeeSchedule::default());
+        assert_eq!(result.best().unwrap().1.id.0, 1);
+
+        // 200 - 10 listing fee - 20 exchange fee - 100
+        let best = result.best_by_roi().unwrap();
+        assert_eq!(best.market.id.0, 2);
+        assert_eq!(best.roi, dec!(0.7));
+        assert_eq!(best.profit_per_capital, dec!(70) / dec!(110));
+        assert_eq!(result.best_by_profit_per_capital().unwrap().market.id.0, 2);
+
+        let by_roi: Vec<usize> = result
+            .iter_by_roi()
+            .map(|entry| entry.market.id.0)
+            .collect();
+        assert_eq!(by_roi, vec![2, 1]);
+    }
+
+    #[test]
+    fn vendor_value_is_a_price_floor() {
+        let market = Market {
+            orderbook: Orderbook::new(
+                [Level {
+                    price: dec!(10),
+                    size: dec!(1),
+                }],
+                [
+                    Level {
+                        price: dec!(12),
+                        size: dec!(1),
+                    },
+                    Level {
+                        price: dec!(30),
+                        size: dec!(1),
+                    },
+                ],
+            ),
+            id: Id(1),
+            vendor_value: None,
+        };
+        let fees = FeeSchedule::default();
+        assert_eq!(calc_market_profit(&market, &fees), Some(Coin(0)));
+        assert_eq!(market.asks_below_vendor_value().count(), 0);
+
+        let market = market.with_vendor_value(dec!(16));
+        assert_eq!(calc_market_profit(&market, &fees), Some(Coin(6)));
+        assert_eq!(
+            market.asks_below_vendor_value().collect::<Vec<_>>(),
+            vec![&Level {
+                price: dec!(12),
+                size: dec!(1),
+            }]
+        );
+
+        let markets = [market];
+        let result = find_profit(&markets, &fees);
+        let best = result.best_by_roi().unwrap();
+        assert!(best.sell_to_vendor);
+        assert_eq!(best.profit_per_capital, dec!(0.6));
+    }
 }
@@ -0,0 +1,157 @@
+//! Sizing positions: splitting a capital budget between opportunities to maximize the
+//! expected profit.
+
+use rust_decimal::Decimal;
+
+use super::{scanner::Opportunity, FeeSchedule};
+use crate::{api::ItemId, coin::Coin};
+
+/// An opportunity to put capital into.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Candidate {
+    pub item_id: ItemId,
+    /// Capital tied up per unit until it sells, e.g. the buy price and listing fee.
+    pub unit_cost: Coin,
+    /// Expected profit per unit once sold.
+    pub unit_profit: Coin,
+    /// Units available at `unit_cost`.
+    pub depth: u32,
+    /// Units expected to sell within the planning horizon. Units beyond that would tie up
+    /// capital without selling.
+    pub velocity: u32,
+}
+
+impl Candidate {
+    /// A candidate for flipping `opportunity`, buying up to `depth` units and selling
+    /// `velocity` units within the planning horizon.
+    pub fn from_opportunity(
+        opportunity: &Opportunity,
+        fees: &FeeSchedule,
+        depth: u32,
+        velocity: u32,
+    ) -> Option<Self> {
+        // Merchants charge no fee
+        let listing_fee = if opportunity.sell_to_vendor {
+            Decimal::ZERO
+        } else {
+            fees.listing_fee(opportunity.sell_price.into())
+        };
+
+        Some(Self {
+            item_id: opportunity.item_id,
+            unit_cost: opportunity.buy_price + Coin::try_from(listing_fee).ok()?,
+            unit_profit: opportunity.profit,
+            depth,
+            velocity,
+        })
+    }
+
+    /// Units worth buying: as many as are available and expected to sell.
+    pub fn max_units(&self) -> u32 {
+        self.depth.min(self.velocity)
+    }
+
+    fn profit_per_capital(&self) -> Decimal {
+        Decimal::from(self.unit_profit) / Decimal::from(self.unit_cost)
+    }
+}
+
+/// The capital given to one candidate.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Allocation {
+    pub item_id: ItemId,
+    pub units: u32,
+    pub capital: Coin,
+    pub expected_profit: Coin,
+}
+
+/// How a budget is split between candidates, see [`allocate`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Plan {
+    /// Best profit per capital first.
+    pub allocations: Vec<Allocation>,
+    pub capital_used: Coin,
+    pub expected_profit: Coin,
+}
+
+/// Splits `budget` between `candidates`, greedily funding the best expected profit per
+/// unit of capital first, up to each candidate's [`Candidate::max_units`]. Candidates
+/// that don't make a profit get nothing. This is optimal if units could be split; with
+/// whole units it can leave part of the budget unused.
+pub fn allocate(budget: Coin, candidates: &[Candidate]) -> Plan {
+    let mut ranked: Vec<&Candidate> = candidates
+        .iter()
+        .filter(|candidate| candidate.unit_cost > Coin::ZERO && candidate.unit_profit > Coin::ZERO)
+        .collect();
+    ranked.sort_by_key(|candidate| std::cmp::Reverse(candidate.profit_per_capital()));
+
+    let mut plan = Plan::default();
+    let mut remaining = budget;
+    for candidate in ranked {
+        let affordable = (remaining.copper() / candidate.unit_cost.copper()).max(0);
+        let units = u32::try_from(affordable)
+            .unwrap_or(u32::MAX)
+            .min(candidate.max_units());
+        if units == 0 {
+            continue;
+        }
+
+        let allocation = Allocation {
+            item_id: candidate.item_id,
+            units,
+            capital: candidate.unit_cost * units,
+            expected_profit: candidate.unit_profit * units,
+        };
+        remaining -= allocation.capital;
+        plan.capital_used += allocation.capital;
+        plan.expected_profit += allocation.expected_profit;
+        plan.allocations.push(allocation);
+    }
+
+    plan
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidate(
+        id: u32,
+        unit_cost: i64,
+        unit_profit: i64,
+        depth: u32,
+        velocity: u32,
+    ) -> Candidate {
+        Candidate {
+            item_id: ItemId(id),
+            unit_cost: Coin(unit_cost),
+            unit_profit: Coin(unit_profit),
+            depth,
+            velocity,
+        }
+    }
+
+    #[test]
+    fn funds_best_returns_first() {
+        let candidates = [
+            // 10% return, plenty of depth
+            candidate(1, 1000, 100, 100, 100),
+            // 50% return, but only 3 units sell
+            candidate(2, 200, 100, 50, 3),
+            // 20% return, limited depth
+            candidate(3, 500, 100, 4, 10),
+            // Loss
+            candidate(4, 10, -1, 100, 100),
+        ];
+
+        let plan = allocate(Coin(5000), &candidates);
+        let units: Vec<(u32, u32)> = plan
+            .allocations
+            .iter()
+            .map(|allocation| (allocation.item_id.0, allocation.units))
+            .collect();
+        assert_eq!(units, vec![(2, 3), (3, 4), (1, 2)]);
+        assert_eq!(plan.capital_used, Coin(600 + 2000 + 2000));
+        assert_eq!(plan.expected_profit, Coin(300 + 400 + 200));
+    }
+}
@@ -0,0 +1,503 @@
+//! Planning crafts: whether each ingredient of an item is cheaper to buy from the trading
+//! post or to craft itself.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::{
+    api::{
+        account::{self, BankSlot, MaterialSlot},
+        prices::{self, Price},
+        recipes::{self, Recipe},
+        ItemId,
+    },
+    client::{Client, GetError},
+    coin::Coin,
+};
+
+/// How to get the items of a [`CraftNode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Acquire {
+    /// Buying from the lowest sell offers is cheapest.
+    Buy,
+    /// Crafting from the node's ingredients is cheapest.
+    Craft,
+    /// The items can neither be bought nor crafted from tradeable ingredients, e.g.
+    /// account bound materials.
+    Unavailable,
+}
+
+/// Crafting a node's items with a recipe.
+#[derive(Debug, Clone)]
+pub struct Craft {
+    pub recipe_id: u32,
+    /// How often the recipe has to be crafted for the node's count.
+    pub crafts: u32,
+    /// One node per ingredient, for the amounts of all crafts.
+    pub ingredients: Vec<CraftNode>,
+    /// The optimal cost of all ingredients, unless one of them is unavailable.
+    pub cost: Option<Coin>,
+}
+
+/// An amount of an item in a craft tree, annotated with whether buying or crafting it is
+/// cheaper.
+#[derive(Debug, Clone)]
+pub struct CraftNode {
+    pub item_id: ItemId,
+    pub count: u32,
+    /// The cost of buying `count` items at the lowest sell offer, if the item is for sale.
+    pub buy_cost: Option<Coin>,
+    /// The item's recipe, unless it can't be crafted or crafting `count` items would take
+    /// more than `u32::MAX` of an ingredient.
+    pub craft: Option<Craft>,
+    pub acquire: Acquire,
+}
+
+impl CraftNode {
+    /// The cost of the cheaper way to get the node's items.
+    pub fn cost(&self) -> Option<Coin> {
+        match self.acquire {
+            Acquire::Buy => self.buy_cost,
+            Acquire::Craft => self.craft.as_ref()?.cost,
+            Acquire::Unavailable => None,
+        }
+    }
+
+    /// The items to buy, following the cheaper choice at every node. Items are listed once
+    /// per node, so the same item can appear more than once.
+    pub fn purchases(&self) -> Vec<(ItemId, u32)> {
+        let mut purchases = Vec::new();
+        self.collect_purchases(&mut purchases);
+        purchases
+    }
+
+    fn collect_purchases(&self, purchases: &mut Vec<(ItemId, u32)>) {
+        match (&self.acquire, &self.craft) {
+            (Acquire::Buy, _) => purchases.push((self.item_id, self.count)),
+            (Acquire::Craft, Some(craft)) => {
+                for ingredient in &craft.ingredients {
+                    ingredient.collect_purchases(purchases);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Builds the craft tree of `count` of `item_id` from the recipes crafting each item and
+/// the lowest sell offer of each item. Recipes that would craft an item from itself are
+/// ignored.
+pub fn plan(
+    item_id: ItemId,
+    count: u32,
+    recipes: &HashMap<ItemId, Recipe>,
+    sell_prices: &HashMap<ItemId, Coin>,
+) -> CraftNode {
+    plan_node(item_id, count, recipes, sell_prices, &mut HashSet::new())
+}
+
+fn plan_node(
+    item_id: ItemId,
+    count: u32,
+    recipes: &HashMap<ItemId, Recipe>,
+    sell_prices: &HashMap<ItemId, Coin>,
+    path: &mut HashSet<ItemId>,
+) -> CraftNode {
+    let buy_cost = sell_prices.get(&item_id).map(|price| *price * count);
+
+    let craft = recipes
+        .get(&item_id)
+        .filter(|_| path.insert(item_id))
+        .and_then(|recipe| {
+            let crafts = count.div_ceil(recipe.output_item_count.max(1));
+            let ingredients: Option<Vec<CraftNode>> = recipe
+                .ingredients
+                .iter()
+                .map(|ingredient| {
+                    let count = ingredient.count.checked_mul(crafts)?;
+                    Some(plan_node(
+                        ingredient.item_id,
+                        count,
+                        recipes,
+                        sell_prices,
+                        path,
+                    ))
+                })
+                .collect();
+            path.remove(&item_id);
+            let ingredients = ingredients?;
+
+            Some(Craft {
+                recipe_id: recipe.id,
+                crafts,
+                cost: ingredients.iter().map(CraftNode::cost).sum(),
+                ingredients,
+            })
+        });
+
+    let acquire = match (buy_cost, craft.as_ref().and_then(|craft| craft.cost)) {
+        (Some(buy), Some(craft)) if craft < buy => Acquire::Craft,
+        (Some(_), _) => Acquire::Buy,
+        (None, Some(_)) => Acquire::Craft,
+        (None, None) => Acquire::Unavailable,
+    };
+
+    CraftNode {
+        item_id,
+        count,
+        buy_cost,
+        craft,
+        acquire,
+    }
+}
+
+/// Fetches the recipes of `item_id` and all its ingredients, and the prices of every item
+/// involved, to plan crafting `count` of it. Items with more than one recipe use the first.
+/// Corresponds to GET /v2/recipes/search, /v2/recipes and /v2/commerce/prices
+pub async fn fetch_plan(
+    client: &Client,
+    item_id: ItemId,
+    count: u32,
+) -> Result<CraftNode, GetError> {
+    let mut recipes = HashMap::new();
+    let mut seen = HashSet::from([item_id]);
+    let mut frontier = vec![item_id];
+
+    while !frontier.is_empty() {
+        let searches = frontier
+            .iter()
+            .map(|item_id| recipes::search_by_output(client, *item_id));
+        let recipe_ids: Vec<u32> = futures_util::future::try_join_all(searches)
+            .await?
+            .into_iter()
+            .filter_map(|ids| ids.first().copied())
+            .collect();
+
+        frontier.clear();
+        for recipe in recipes::get_recipes_chunked(client, &recipe_ids)
+            .await?
+            .found
+        {
+            for ingredient in &recipe.ingredients {
+                if seen.insert(ingredient.item_id) {
+                    frontier.push(ingredient.item_id);
+                }
+            }
+            recipes.insert(recipe.output_item_id, recipe);
+        }
+    }
+
+    let items: Vec<ItemId> = seen.into_iter().collect();
+    let sell_prices = prices::get_prices_chunked(client, &items)
+        .await?
+        .found
+        .into_iter()
+        .filter(|price| price.sells.quantity > 0)
+        .map(|price| (price.id, price.sells.unit_price))
+        .collect();
+
+    Ok(plan(item_id, count, &recipes, &sell_prices))
+}
+
+/// An item to buy for a set of crafts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShoppingItem {
+    pub item_id: ItemId,
+    /// How many the crafts take.
+    pub needed: u32,
+    /// How many of those are already owned.
+    pub owned: u32,
+    /// How many are left to buy.
+    pub to_buy: u32,
+    /// The cost of buying at the lowest sell offer, if the item is offered.
+    pub instant_buy_cost: Option<Coin>,
+    /// The cost of buy orders 1 copper above the highest buy order, if there is one.
+    pub buy_order_cost: Option<Coin>,
+}
+
+/// The ingredients to buy for a set of crafts, see [`shopping_list`].
+#[derive(Debug, Clone, Default)]
+pub struct ShoppingList {
+    /// Sorted by item id.
+    pub items: Vec<ShoppingItem>,
+    /// Ids of requested recipes that don't exist, whose crafts the list leaves out. In the
+    /// order they were requested.
+    pub missing_recipes: Vec<u32>,
+}
+
+impl ShoppingList {
+    /// The cost of buying everything at the lowest sell offers, unless an item isn't offered.
+    pub fn instant_buy_cost(&self) -> Option<Coin> {
+        self.items.iter().map(|item| item.instant_buy_cost).sum()
+    }
+
+    /// The cost of buying everything with buy orders, unless an item has none.
+    pub fn buy_order_cost(&self) -> Option<Coin> {
+        self.items.iter().map(|item| item.buy_order_cost).sum()
+    }
+}
+
+/// Counts the items in the account's bank and material storage.
+pub fn owned_items(bank: &[Option<BankSlot>], materials: &[MaterialSlot]) -> HashMap<ItemId, u32> {
+    let mut owned = HashMap::new();
+    let slots = bank.iter().flatten().map(|slot| (slot.id, slot.count));
+    for (item_id, count) in slots.chain(materials.iter().map(|slot| (slot.id, slot.count))) {
+        *owned.entry(item_id).or_default() += count;
+    }
+    owned
+}
+
+/// Aggregates the ingredients of crafting each recipe the given number of times, and nets
+/// out `owned` items. Needed counts saturate at `u32::MAX`.
+pub fn shopping_list<'a, Crafts>(
+    crafts: Crafts,
+    owned: &HashMap<ItemId, u32>,
+    prices: &HashMap<ItemId, Price>,
+) -> ShoppingList
+where
+    Crafts: IntoIterator<Item = (&'a Recipe, u32)>,
+{
+    let mut needed = std::collections::BTreeMap::<ItemId, u32>::new();
+    for (recipe, count) in crafts {
+        for ingredient in &recipe.ingredients {
+            let needed = needed.entry(ingredient.item_id).or_default();
+            *needed = needed.saturating_add(ingredient.count.saturating_mul(count));
+        }
+    }
+
+    let items = needed
+        .into_iter()
+        .filter_map(|(item_id, needed)| {
+            let owned = owned.get(&item_id).copied().unwrap_or(0).min(needed);
+            let to_buy = needed - owned;
+            if to_buy == 0 {
+                return None;
+            }
+
+            let price = prices.get(&item_id);
+            Some(ShoppingItem {
+                item_id,
+                needed,
+                owned,
+                to_buy,
+                instant_buy_cost: price
+                    .filter(|price| price.sells.quantity > 0)
+                    .map(|price| price.sells.unit_price * to_buy),
+                buy_order_cost: price
+                    .filter(|price| price.buys.quantity > 0)
+                    .map(|price| (price.buys.unit_price + Coin(1)) * to_buy),
+            })
+        })
+        .collect();
+
+    ShoppingList {
+        items,
+        missing_recipes: Vec::new(),
+    }
+}
+
+/// Like [`shopping_list`], for crafts given as recipe id and number of crafts. Ids missing
+/// from `recipes` are reported in [`ShoppingList::missing_recipes`].
+pub fn shopping_list_by_recipe_id(
+    crafts: &[(u32, u32)],
+    recipes: &HashMap<u32, Recipe>,
+    owned: &HashMap<ItemId, u32>,
+    prices: &HashMap<ItemId, Price>,
+) -> ShoppingList {
+    let mut missing_recipes = Vec::new();
+    let mut found = Vec::new();
+    for (recipe_id, count) in crafts {
+        match recipes.get(recipe_id) {
+            Some(recipe) => found.push((recipe, *count)),
+            None if !missing_recipes.contains(recipe_id) => missing_recipes.push(*recipe_id),
+            None => {}
+        }
+    }
+
+    ShoppingList {
+        missing_recipes,
+        ..shopping_list(found, owned, prices)
+    }
+}
+
+/// Fetches the recipes of `crafts` (recipe id and number of crafts), the account's bank
+/// and material storage, and the prices of the ingredients, to build a shopping list.
+/// Recipe ids the API doesn't know are reported in [`ShoppingList::missing_recipes`].
+/// Corresponds to GET /v2/recipes, /v2/account/bank, /v2/account/materials and
+/// /v2/commerce/prices
+/// Requires authentication: 'account', 'inventories' scopes.
+pub async fn fetch_shopping_list(
+    client: &Client,
+    crafts: &[(u32, u32)],
+) -> Result<ShoppingList, GetError> {
+    let recipe_ids: Vec<u32> = crafts.iter().map(|(recipe_id, _)| *recipe_id).collect();
+    let recipes: HashMap<u32, Recipe> = recipes::get_recipes_chunked(client, &recipe_ids)
+        .await?
+        .found
+        .into_iter()
+        .map(|recipe| (recipe.id, recipe))
+        .collect();
+
+    let (bank, materials) =
+        futures_util::future::try_join(account::get_bank(client), account::get_materials(client))
+            .await?;
+    let owned = owned_items(&bank, &materials);
+
+    let ingredients: Vec<ItemId> = crafts
+        .iter()
+        .filter_map(|(recipe_id, _)| recipes.get(recipe_id))
+        .flat_map(|recipe| {
+            recipe
+                .ingredients
+                .iter()
+                .map(|ingredient| ingredient.item_id)
+        })
+        .collect();
+    let prices = prices::get_prices_chunked(client, &ingredients)
+        .await?
+        .found
+        .into_iter()
+        .map(|price| (price.id, price))
+        .collect();
+
+    Ok(shopping_list_by_recipe_id(
+        crafts, &recipes, &owned, &prices,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn recipe(id: u32, output: u32, count: u32, ingredients: &[(u32, u32)]) -> Recipe {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "type": "Refinement",
+            "output_item_id": output,
+            "output_item_count": count,
+            "time_to_craft_ms": 1000,
+            "disciplines": ["Weaponsmith"],
+            "min_rating": 0,
+            "flags": [],
+            "ingredients": ingredients
+                .iter()
+                .map(|(item_id, count)| serde_json::json!({ "item_id": item_id, "count": count }))
+                .collect::<Vec<_>>(),
+            "chat_link": "[&CQEAAAA=]",
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn ingredient_count_overflow_falls_back_to_buying() {
+        let recipes = HashMap::from([(ItemId(1), recipe(10, 1, 1, &[(2, 2)]))]);
+        let sell_prices = HashMap::from([(ItemId(1), Coin(1)), (ItemId(2), Coin(1))]);
+
+        let tree = plan(ItemId(1), u32::MAX, &recipes, &sell_prices);
+        assert!(tree.craft.is_none());
+        assert_eq!(tree.acquire, Acquire::Buy);
+    }
+
+    #[test]
+    fn picks_cheaper_option_per_node() {
+        // 1 is crafted from 2 (x2) and 3 (x1); 2 is crafted in batches of 5 from 4 (x10)
+        let recipes = HashMap::from([
+            (ItemId(1), recipe(10, 1, 1, &[(2, 2), (3, 1)])),
+            (ItemId(2), recipe(20, 2, 5, &[(4, 10)])),
+            (ItemId(4), recipe(40, 4, 1, &[(2, 1)])),
+        ]);
+        let sell_prices = HashMap::from([
+            (ItemId(1), Coin(1000)),
+            (ItemId(2), Coin(100)),
+            (ItemId(3), Coin(50)),
+            (ItemId(4), Coin(5)),
+        ]);
+
+        let tree = plan(ItemId(1), 3, &recipes, &sell_prices);
+        assert_eq!(tree.acquire, Acquire::Craft);
+
+        let craft = tree.craft.as_ref().unwrap();
+        assert_eq!(craft.crafts, 3);
+        let item_2 = &craft.ingredients[0];
+        assert_eq!(item_2.count, 6);
+        // 2 crafts of 10 at 5c beat buying 6 at 1s
+        assert_eq!(item_2.buy_cost, Some(Coin(600)));
+        assert_eq!(item_2.cost(), Some(Coin(100)));
+        assert_eq!(item_2.acquire, Acquire::Craft);
+        // Crafting 2 from 4 from 2 would be circular
+        let item_4 = &item_2.craft.as_ref().unwrap().ingredients[0];
+        assert!(item_4.craft.as_ref().unwrap().ingredients[0]
+            .craft
+            .is_none());
+
+        assert_eq!(tree.cost(), Some(Coin(100 + 150)));
+        assert_eq!(tree.purchases(), vec![(ItemId(4), 20), (ItemId(3), 3)]);
+    }
+
+    #[test]
+    fn shopping_list_nets_out_owned_items() {
+        let sword = recipe(10, 1, 1, &[(2, 3), (3, 1)]);
+        let axe = recipe(11, 5, 1, &[(2, 2), (4, 1)]);
+        let owned = HashMap::from([(ItemId(2), 4), (ItemId(4), 9)]);
+        let price = |id: u32, buy: u32, sell: u32| -> (ItemId, Price) {
+            let price = serde_json::from_value(serde_json::json!({
+                "id": id,
+                "buys": { "unit_price": buy, "quantity": 10 },
+                "sells": { "unit_price": sell, "quantity": 10 },
+            }))
+            .unwrap();
+            (ItemId(id), price)
+        };
+        let prices = HashMap::from([price(2, 80, 100), price(3, 10, 20)]);
+
+        let list = shopping_list([(&sword, 2), (&axe, 1)], &owned, &prices);
+        assert_eq!(
+            list.items,
+            vec![
+                ShoppingItem {
+                    item_id: ItemId(2),
+                    needed: 8,
+                    owned: 4,
+                    to_buy: 4,
+                    instant_buy_cost: Some(Coin(400)),
+                    buy_order_cost: Some(Coin(324)),
+                },
+                ShoppingItem {
+                    item_id: ItemId(3),
+                    needed: 2,
+                    owned: 0,
+                    to_buy: 2,
+                    instant_buy_cost: Some(Coin(40)),
+                    buy_order_cost: Some(Coin(22)),
+                },
+            ]
+        );
+        assert_eq!(list.instant_buy_cost(), Some(Coin(440)));
+        assert!(list.missing_recipes.is_empty());
+
+        let recipes = HashMap::from([(10, sword)]);
+        let list = shopping_list_by_recipe_id(
+            &[(99, 1), (10, 1), (99, 2)],
+            &recipes,
+            &HashMap::new(),
+            &prices,
+        );
+        assert_eq!(list.missing_recipes, vec![99]);
+        let needed: Vec<(ItemId, u32)> = list
+            .items
+            .iter()
+            .map(|item| (item.item_id, item.needed))
+            .collect();
+        assert_eq!(needed, vec![(ItemId(2), 3), (ItemId(3), 1)]);
+    }
+
+    #[test]
+    fn unavailable_ingredients_force_buying() {
+        let recipes = HashMap::from([(ItemId(1), recipe(10, 1, 1, &[(2, 1), (3, 1)]))]);
+        let sell_prices = HashMap::from([(ItemId(1), Coin(1000)), (ItemId(3), Coin(1))]);
+
+        let tree = plan(ItemId(1), 1, &recipes, &sell_prices);
+        assert_eq!(tree.craft.as_ref().unwrap().cost, None);
+        assert_eq!(tree.acquire, Acquire::Buy);
+        assert_eq!(tree.cost(), Some(Coin(1000)));
+    }
+}
@@ -0,0 +1,207 @@
+//! Converting between gold and gems through the currency exchange, whose rate gets worse
+//! the larger the exchange.
+
+use std::sync::Mutex;
+
+use rust_decimal::Decimal;
+
+use crate::{
+    api::exchange,
+    client::{Client, GetError},
+    coin::Coin,
+    runtime::SystemTime,
+};
+
+/// Which way an exchange converts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    CoinsToGems,
+    GemsToCoins,
+}
+
+/// A quote of the currency exchange for a given amount.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GemQuote {
+    pub direction: Direction,
+    /// Copper spent when buying gems, gems spent when selling them.
+    pub spent: u64,
+    /// Gems received when buying gems, copper received when selling them.
+    pub received: u64,
+    /// The rate the exchange reported for the amount.
+    pub coins_per_gem: Coin,
+    pub quoted_at: SystemTime,
+}
+
+impl GemQuote {
+    /// The copper paid or received per gem, after the exchange's rounding.
+    pub fn effective_coins_per_gem(&self) -> Option<Decimal> {
+        let (coins, gems) = match self.direction {
+            Direction::CoinsToGems => (self.spent, self.received),
+            Direction::GemsToCoins => (self.received, self.spent),
+        };
+        Decimal::from(coins).checked_div(Decimal::from(gems))
+    }
+
+    /// The gold cost of `gems` at this quote's effective rate, e.g. the price of a gem store
+    /// item bought with exchanged gems.
+    pub fn cost_of_gems(&self, gems: u32) -> Option<Coin> {
+        let coins = self.effective_coins_per_gem()? * Decimal::from(gems);
+        Coin::try_from(coins.ceil()).ok()
+    }
+}
+
+/// Receives every quote fetched through this module, e.g. to store exchange rates over
+/// time.
+pub trait RateHistory: Send + Sync {
+    fn record(&self, quote: &GemQuote);
+}
+
+/// Keeps quotes in memory.
+#[derive(Debug, Default)]
+pub struct MemoryHistory {
+    quotes: Mutex<Vec<GemQuote>>,
+}
+
+impl MemoryHistory {
+    /// Every quote recorded, oldest first.
+    pub fn quotes(&self) -> Vec<GemQuote> {
+        self.quotes.lock().expect("history lock poisoned").clone()
+    }
+}
+
+impl RateHistory for MemoryHistory {
+    fn record(&self, quote: &GemQuote) {
+        self.quotes
+            .lock()
+            .expect("history lock poisoned")
+            .push(*quote);
+    }
+}
+
+/// Fetches a quote for buying gems with `coins`.
+/// Corresponds to GET /v2/commerce/exchange/coins
+pub async fn quote_coins_to_gems(
+    client: &Client,
+    coins: Coin,
+    history: Option<&dyn RateHistory>,
+) -> Result<GemQuote, GetError> {
+    let exchange = exchange::get_coins_to_gems(client, coins).await?;
+    let quote = GemQuote {
+        direction: Direction::CoinsToGems,
+        spent: coins.copper().max(0) as u64,
+        received: exchange.quantity,
+        coins_per_gem: exchange.coins_per_gem,
+        quoted_at: SystemTime::now(),
+    };
+
+    if let Some(history) = history {
+        history.record(&quote);
+    }
+    Ok(quote)
+}
+
+/// Fetches a quote for selling `gems`.
+/// Corresponds to GET /v2/commerce/exchange/gems
+pub async fn quote_gems_to_coins(
+    client: &Client,
+    gems: u32,
+    history: Option<&dyn RateHistory>,
+) -> Result<GemQuote, GetError> {
+    let exchange = exchange::get_gems_to_coins(client, gems).await?;
+    let quote = GemQuote {
+        direction: Direction::GemsToCoins,
+        spent: gems.into(),
+        received: exchange.quantity,
+        coins_per_gem: exchange.coins_per_gem,
+        quoted_at: SystemTime::now(),
+    };
+
+    if let Some(history) = history {
+        history.record(&quote);
+    }
+    Ok(quote)
+}
+
+/// Fetches quotes for buying gems with each of `amounts`, to see how the rate worsens with
+/// larger exchanges.
+/// Corresponds to GET /v2/commerce/exchange/coins
+pub async fn coins_to_gems_curve(
+    client: &Client,
+    amounts: &[Coin],
+    history: Option<&dyn RateHistory>,
+) -> Result<Vec<GemQuote>, GetError> {
+    let quotes = amounts
+        .iter()
+        .map(|coins| quote_coins_to_gems(client, *coins, history));
+
+    futures_util::future::try_join_all(quotes).await
+}
+
+/// The share of gold lost by buying gems and selling them right away, e.g. 0.15 for 15%.
+pub fn round_trip_loss(buy: &GemQuote, sell: &GemQuote) -> Option<Decimal> {
+    let bought = buy.effective_coins_per_gem()?;
+    let sold = sell.effective_coins_per_gem()?;
+    Some(Decimal::ONE - sold / bought)
+}
+
+/// The cheaper way to get an item sold both in the gem store and on the trading post.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Route {
+    /// Exchanging gold for gems and buying from the gem store, for the given cost.
+    GemStore(Coin),
+    /// Buying from the trading post, for the given cost.
+    TradingPost(Coin),
+}
+
+/// Compares buying an item for `gem_price` gems with gold exchanged at `rate`, with buying
+/// it from the trading post for `trading_post_price`, if it's tradeable.
+pub fn cheapest_route(
+    gem_price: u32,
+    rate: &GemQuote,
+    trading_post_price: Option<Coin>,
+) -> Option<Route> {
+    let via_gems = rate.cost_of_gems(gem_price);
+    match (via_gems, trading_post_price) {
+        (Some(gems), Some(trading_post)) if trading_post < gems => {
+            Some(Route::TradingPost(trading_post))
+        }
+        (Some(gems), _) => Some(Route::GemStore(gems)),
+        (None, trading_post) => trading_post.map(Route::TradingPost),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn quote(direction: Direction, spent: u64, received: u64) -> GemQuote {
+        GemQuote {
+            direction,
+            spent,
+            received,
+            coins_per_gem: Coin(0),
+            quoted_at: SystemTime::UNIX_EPOCH,
+        }
+    }
+
+    #[test]
+    fn compares_gem_routes() {
+        // 100g for 400 gems, 400 gems for 75g
+        let buy = quote(Direction::CoinsToGems, 1_000_000, 400);
+        let sell = quote(Direction::GemsToCoins, 400, 750_000);
+
+        assert_eq!(buy.effective_coins_per_gem(), Some(dec!(2500)));
+        assert_eq!(buy.cost_of_gems(125), Some(Coin::new(31, 25, 0)));
+        assert_eq!(round_trip_loss(&buy, &sell), Some(dec!(0.25)));
+
+        assert_eq!(
+            cheapest_route(125, &buy, Some(Coin::new(30, 0, 0))),
+            Some(Route::TradingPost(Coin::new(30, 0, 0)))
+        );
+        assert_eq!(
+            cheapest_route(125, &buy, None),
+            Some(Route::GemStore(Coin::new(31, 25, 0)))
+        );
+    }
+}
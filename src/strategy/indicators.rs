@@ -0,0 +1,305 @@
+//! Technical indicators over price history, for following trends rather than flipping the
+//! current spread.
+
+use rust_decimal::{Decimal, MathematicalOps};
+
+use crate::{api::prices::Price, runtime::SystemTime};
+
+/// Which side of the market a price is taken from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Side {
+    /// The highest buy order.
+    Buy,
+    /// The lowest sell offer.
+    Sell,
+    /// Halfway between the highest buy order and the lowest sell offer.
+    Mid,
+}
+
+/// Values over time, oldest first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Series {
+    points: Vec<(SystemTime, Decimal)>,
+}
+
+impl Series {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The prices of one side of the market from snapshots of an item's prices, e.g. taken
+    /// every few minutes.
+    pub fn from_prices<'a, Snapshots>(snapshots: Snapshots, side: Side) -> Self
+    where
+        Snapshots: IntoIterator<Item = (SystemTime, &'a Price)>,
+    {
+        snapshots
+            .into_iter()
+            .map(|(at, price)| {
+                let value = match side {
+                    Side::Buy => price.buys.unit_price.into(),
+                    Side::Sell => price.sells.unit_price.into(),
+                    Side::Mid => {
+                        Decimal::from(price.buys.unit_price + price.sells.unit_price) / Decimal::TWO
+                    }
+                };
+                (at, value)
+            })
+            .collect()
+    }
+
+    /// Appends a value, which should be newer than the last one.
+    pub fn push(&mut self, at: SystemTime, value: Decimal) {
+        self.points.push((at, value));
+    }
+
+    pub fn len(&self) -> usize {
+        self.points.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.points.is_empty()
+    }
+
+    pub fn last(&self) -> Option<(SystemTime, Decimal)> {
+        self.points.last().copied()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (SystemTime, Decimal)> + '_ {
+        self.points.iter().copied()
+    }
+
+    pub fn values(&self) -> impl Iterator<Item = Decimal> + '_ {
+        self.points.iter().map(|(_, value)| *value)
+    }
+
+    /// Maps every full window of `period` values to one value, stamped with the window's
+    /// last time.
+    fn windows(&self, period: usize, f: impl Fn(&[(SystemTime, Decimal)]) -> Decimal) -> Series {
+        if period == 0 {
+            return Series::new();
+        }
+
+        self.points
+            .windows(period)
+            .map(|window| (window[period - 1].0, f(window)))
+            .collect()
+    }
+
+    /// Simple moving average over `period` values.
+    pub fn sma(&self, period: usize) -> Series {
+        self.windows(period, |window| {
+            mean(window.iter().map(|(_, value)| *value))
+        })
+    }
+
+    /// Exponential moving average with smoothing 2 / (`period` + 1), seeded with the simple
+    /// average of the first `period` values.
+    pub fn ema(&self, period: usize) -> Series {
+        if period == 0 || self.len() < period {
+            return Series::new();
+        }
+
+        let alpha = Decimal::TWO / Decimal::from(period + 1);
+        let mut ema = mean(self.points[..period].iter().map(|(_, value)| *value));
+        let mut series = Series::new();
+        series.push(self.points[period - 1].0, ema);
+        for (at, value) in &self.points[period..] {
+            ema += alpha * (*value - ema);
+            series.push(*at, ema);
+        }
+        series
+    }
+
+    /// Relative strength index over `period` changes, from 0 to 100, using Wilder's
+    /// smoothing. High values mean recent gains outweigh losses.
+    pub fn rsi(&self, period: usize) -> Series {
+        if period == 0 || self.len() <= period {
+            return Series::new();
+        }
+
+        let changes: Vec<Decimal> = self.points.windows(2).map(|w| w[1].1 - w[0].1).collect();
+        let gain = |change: &Decimal| (*change).max(Decimal::ZERO);
+        let loss = |change: &Decimal| (-*change).max(Decimal::ZERO);
+
+        let period_len = Decimal::from(period);
+        let mut avg_gain = mean(changes[..period].iter().map(gain));
+        let mut avg_loss = mean(changes[..period].iter().map(loss));
+        let rsi = |avg_gain: Decimal, avg_loss: Decimal| {
+            if avg_loss.is_zero() {
+                Decimal::ONE_HUNDRED
+            } else {
+                Decimal::ONE_HUNDRED - Decimal::ONE_HUNDRED / (Decimal::ONE + avg_gain / avg_loss)
+            }
+        };
+
+        let mut series = Series::new();
+        series.push(self.points[period].0, rsi(avg_gain, avg_loss));
+        for (change, (at, _)) in changes[period..].iter().zip(&self.points[period + 1..]) {
+            avg_gain = (avg_gain * (period_len - Decimal::ONE) + gain(change)) / period_len;
+            avg_loss = (avg_loss * (period_len - Decimal::ONE) + loss(change)) / period_len;
+            series.push(*at, rsi(avg_gain, avg_loss));
+        }
+        series
+    }
+
+    /// Bollinger bands: the simple moving average over `period` values, plus and minus `k`
+    /// standard deviations.
+    pub fn bollinger(&self, period: usize, k: Decimal) -> Vec<Band> {
+        self.sma(period)
+            .iter()
+            .zip(self.points.windows(period.max(1)))
+            .map(|((at, middle), window)| {
+                let deviation = std_dev(window.iter().map(|(_, value)| *value), middle) * k;
+                Band {
+                    at,
+                    lower: middle - deviation,
+                    middle,
+                    upper: middle + deviation,
+                }
+            })
+            .collect()
+    }
+
+    /// The change from `periods` values earlier, in percent.
+    pub fn percent_change(&self, periods: usize) -> Series {
+        if periods == 0 {
+            return Series::new();
+        }
+
+        self.points
+            .windows(periods + 1)
+            .filter(|window| !window[0].1.is_zero())
+            .map(|window| {
+                let (at, current) = window[periods];
+                (
+                    at,
+                    (current - window[0].1) / window[0].1 * Decimal::ONE_HUNDRED,
+                )
+            })
+            .collect()
+    }
+
+    /// Volatility over `period` changes: the standard deviation of the percent changes
+    /// between consecutive values.
+    pub fn volatility(&self, period: usize) -> Series {
+        let changes = self.percent_change(1);
+        changes.windows(period, |window| {
+            let changes = window.iter().map(|(_, change)| *change);
+            std_dev(changes.clone(), mean(changes))
+        })
+    }
+
+    /// Stability over `period` changes: the share of consecutive values that are equal, from
+    /// 0 if the value changed every time to 1 if it never changed.
+    pub fn stability(&self, period: usize) -> Series {
+        if period == 0 {
+            return Series::new();
+        }
+
+        self.windows(period + 1, |window| {
+            let unchanged = window.windows(2).filter(|w| w[0].1 == w[1].1).count();
+            Decimal::from(unchanged) / Decimal::from(period)
+        })
+    }
+}
+
+impl FromIterator<(SystemTime, Decimal)> for Series {
+    fn from_iter<I: IntoIterator<Item = (SystemTime, Decimal)>>(iter: I) -> Self {
+        Self {
+            points: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Bollinger bands at a point in time, see [`Series::bollinger`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Band {
+    pub at: SystemTime,
+    pub lower: Decimal,
+    pub middle: Decimal,
+    pub upper: Decimal,
+}
+
+fn std_dev(values: impl Iterator<Item = Decimal>, mean_value: Decimal) -> Decimal {
+    mean(values.map(|value| (value - mean_value).powi(2)))
+        .sqrt()
+        .unwrap_or_default()
+}
+
+fn mean(values: impl Iterator<Item = Decimal>) -> Decimal {
+    let (sum, count) = values.fold((Decimal::ZERO, 0u32), |(sum, count), value| {
+        (sum + value, count + 1)
+    });
+    if count == 0 {
+        Decimal::ZERO
+    } else {
+        sum / Decimal::from(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn series(values: &[Decimal]) -> Series {
+        values
+            .iter()
+            .enumerate()
+            .map(|(i, value)| {
+                (
+                    SystemTime::UNIX_EPOCH + Duration::from_secs(i as u64),
+                    *value,
+                )
+            })
+            .collect()
+    }
+
+    fn values(series: &Series) -> Vec<Decimal> {
+        series.values().map(|value| value.round_dp(4)).collect()
+    }
+
+    #[test]
+    fn moving_averages() {
+        let prices = series(&[dec!(10), dec!(11), dec!(12), dec!(13), dec!(20)]);
+
+        assert_eq!(values(&prices.sma(3)), vec![dec!(11), dec!(12), dec!(15)]);
+        // Smoothing of 0.5, seeded with 11
+        assert_eq!(values(&prices.ema(3)), vec![dec!(11), dec!(12), dec!(16)]);
+        assert_eq!(
+            values(&prices.percent_change(2)),
+            vec![dec!(20), dec!(18.1818), dec!(66.6667)]
+        );
+        assert!(prices.sma(6).is_empty());
+
+        let bands = prices.bollinger(3, dec!(2));
+        assert_eq!(bands[0].middle, dec!(11));
+        assert_eq!(bands[0].upper.round_dp(4), dec!(12.6330));
+        assert_eq!(bands[0].lower.round_dp(4), dec!(9.3670));
+    }
+
+    #[test]
+    fn volatility_and_stability() {
+        let flat = series(&[dec!(10), dec!(10), dec!(10), dec!(10)]);
+        assert_eq!(values(&flat.volatility(2)), vec![dec!(0), dec!(0)]);
+        assert_eq!(values(&flat.stability(3)), vec![dec!(1)]);
+
+        // Changes of +10% and -10%
+        let swinging = series(&[dec!(100), dec!(110), dec!(99), dec!(99)]);
+        assert_eq!(values(&swinging.volatility(2)), vec![dec!(10), dec!(5)]);
+        assert_eq!(values(&swinging.stability(2)), vec![dec!(0), dec!(0.5)]);
+    }
+
+    #[test]
+    fn rsi_ranges_from_losses_to_gains() {
+        let rising = series(&[dec!(1), dec!(2), dec!(3), dec!(4)]);
+        assert_eq!(values(&rising.rsi(2)), vec![dec!(100), dec!(100)]);
+
+        let mixed = series(&[dec!(10), dec!(12), dec!(11), dec!(10)]);
+        // Averages of 1 gain and 0.5 loss, then 0.5 and 0.75
+        assert_eq!(values(&mixed.rsi(2)), vec![dec!(66.6667), dec!(40)]);
+    }
+}
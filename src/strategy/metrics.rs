@@ -0,0 +1,126 @@
+//! Per-item metrics of how an item's prices behave, to tell steady markets from ones where
+//! a flip is likely to be undercut or stuck.
+
+use rust_decimal::Decimal;
+
+use super::indicators::{Series, Side};
+use crate::{
+    api::{prices::Price, ItemId},
+    coin::Coin,
+    runtime::SystemTime,
+};
+
+/// How an item's prices behaved over a window of snapshots.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ItemMetrics {
+    pub item_id: ItemId,
+    /// The spread of the latest snapshot, see [`spread_percent`].
+    pub spread_percent: Option<Decimal>,
+    /// The standard deviation of the percent changes of the mid-price, see
+    /// [`Series::volatility`].
+    pub volatility: Option<Decimal>,
+    /// How often the highest buy order stayed the same, from 0 to 1, see
+    /// [`Series::stability`].
+    pub bid_stability: Option<Decimal>,
+    /// How often the lowest sell offer stayed the same, from 0 to 1.
+    pub ask_stability: Option<Decimal>,
+}
+
+impl ItemMetrics {
+    /// The metrics over the last `window` changes of an item's prices, from snapshots taken
+    /// e.g. every few minutes, oldest first. Metrics needing more snapshots than there are
+    /// are `None`.
+    pub fn from_history<'a, Snapshots>(snapshots: Snapshots, window: usize) -> Option<Self>
+    where
+        Snapshots: IntoIterator<Item = (SystemTime, &'a Price)>,
+    {
+        let snapshots: Vec<_> = snapshots.into_iter().collect();
+        let (_, latest) = snapshots.last()?;
+        let series = |side| Series::from_prices(snapshots.iter().copied(), side);
+        let last = |series: Series| series.last().map(|(_, value)| value);
+
+        // Items without buy orders or sell offers have no meaningful mid-price
+        let quoted = snapshots
+            .iter()
+            .copied()
+            .filter(|(_, price)| spread_percent(price).is_some());
+
+        Some(Self {
+            item_id: latest.id,
+            spread_percent: spread_percent(latest),
+            volatility: last(Series::from_prices(quoted, Side::Mid).volatility(window)),
+            bid_stability: last(series(Side::Buy).stability(window)),
+            ask_stability: last(series(Side::Sell).stability(window)),
+        })
+    }
+}
+
+/// The gap between the lowest sell offer and the highest buy order as a percentage of the
+/// price halfway between them, or `None` if either side is empty.
+pub fn spread_percent(price: &Price) -> Option<Decimal> {
+    spread_percent_between(price.buys.unit_price, price.sells.unit_price)
+}
+
+/// The gap between `sell` and `buy` as a percentage of the price halfway between them, or
+/// `None` unless both are positive.
+pub fn spread_percent_between(buy: Coin, sell: Coin) -> Option<Decimal> {
+    if buy <= Coin::ZERO || sell <= Coin::ZERO {
+        return None;
+    }
+
+    let (buy, sell) = (Decimal::from(buy), Decimal::from(sell));
+    Some((sell - buy) / ((sell + buy) / Decimal::TWO) * Decimal::ONE_HUNDRED)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    fn price(buy: u32, sell: u32) -> Price {
+        serde_json::from_value(serde_json::json!({
+            "id": 7,
+            "whitelisted": true,
+            "buys": { "unit_price": buy, "quantity": 100 },
+            "sells": { "unit_price": sell, "quantity": 50 },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn metrics_over_history() {
+        let prices = [
+            price(90, 110),
+            price(90, 110),
+            price(99, 121),
+            price(90, 110),
+        ];
+        let history = prices.iter().enumerate().map(|(i, price)| {
+            (
+                SystemTime::UNIX_EPOCH + Duration::from_secs(i as u64),
+                price,
+            )
+        });
+
+        let metrics = ItemMetrics::from_history(history.clone(), 3).unwrap();
+        assert_eq!(metrics.item_id, ItemId(7));
+        assert_eq!(metrics.spread_percent, Some(dec!(20)));
+        // Mid-price changes of 0%, +10% and -9.09%
+        assert_eq!(metrics.volatility.unwrap().round_dp(2), dec!(7.80));
+        assert_eq!(metrics.bid_stability.unwrap().round_dp(4), dec!(0.3333));
+        assert_eq!(metrics.ask_stability.unwrap().round_dp(4), dec!(0.3333));
+
+        let metrics = ItemMetrics::from_history(history.clone(), 4).unwrap();
+        assert_eq!(metrics.volatility, None);
+
+        let metrics = ItemMetrics::from_history(history, 0).unwrap();
+        assert_eq!(metrics.spread_percent, Some(dec!(20)));
+        assert_eq!(metrics.volatility, None);
+        assert_eq!(metrics.bid_stability, None);
+        assert_eq!(metrics.ask_stability, None);
+        assert_eq!(spread_percent(&price(0, 110)), None);
+        assert_eq!(spread_percent_between(Coin::ZERO, Coin::ZERO), None);
+    }
+}
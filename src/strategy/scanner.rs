@@ -0,0 +1,526 @@
+//! Scanning the whole trading post for items worth flipping: buying with a buy order at the
+//! highest bid and relisting at the lowest sell offer.
+
+use std::collections::{HashMap, HashSet};
+
+use rust_decimal::Decimal;
+
+use super::{
+    metrics::{self, ItemMetrics},
+    FeeSchedule,
+};
+use crate::{
+    api::{prices::Price, ItemId},
+    client::{Client, GetError, PaginatedGetError},
+    coin::Coin,
+};
+
+/// An item that can be flipped at a profit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Opportunity {
+    pub item_id: ItemId,
+    /// The highest buy order, which a new buy order has to match.
+    pub buy_price: Coin,
+    /// The lowest sell offer, which a new listing has to match.
+    pub sell_price: Coin,
+    /// Whether selling to a merchant pays more than listing at `sell_price` after fees, in
+    /// which case `profit` is that of selling to a merchant.
+    pub sell_to_vendor: bool,
+    /// Profit per item after fees.
+    pub profit: Coin,
+    /// Profit relative to the buy price, e.g. 0.25 for 25%.
+    pub roi: Decimal,
+    /// Items wanted at the highest buy order.
+    pub buy_quantity: u32,
+    /// Items offered at the lowest sell offer.
+    pub sell_quantity: u32,
+}
+
+impl Opportunity {
+    /// The opportunity of flipping an item at its current prices, if it has both buy orders
+    /// and sell offers. Items are never sold for less than `vendor_value`, what a merchant
+    /// pays for them (see [`Item::vendor_price`](crate::api::items::Item::vendor_price)).
+    pub fn from_price(
+        price: &Price,
+        vendor_value: Option<Coin>,
+        fees: &FeeSchedule,
+    ) -> Option<Self> {
+        let buy_price = price.buys.unit_price;
+        let sell_price = price.sells.unit_price;
+        if buy_price <= Coin::ZERO || sell_price <= Coin::ZERO {
+            return None;
+        }
+
+        let buy = Decimal::from(buy_price);
+        let listed = fees.net_proceeds(sell_price.into());
+        let vendor = vendor_value.map_or(Decimal::ZERO, Decimal::from);
+        let sell_to_vendor = vendor > listed;
+        let profit = listed.max(vendor) - buy;
+
+        Some(Self {
+            item_id: price.id,
+            buy_price,
+            sell_price,
+            sell_to_vendor,
+            profit: Coin::try_from(profit).ok()?,
+            roi: profit / buy,
+            buy_quantity: price.buys.quantity,
+            sell_quantity: price.sells.quantity,
+        })
+    }
+
+    /// The gap between the sell and buy price as a percentage of the price halfway between
+    /// them, or `None` unless both are positive.
+    pub fn spread_percent(&self) -> Option<Decimal> {
+        metrics::spread_percent_between(self.buy_price, self.sell_price)
+    }
+}
+
+/// Criteria opportunities have to meet, to keep illiquid or unaffordable items out of scan
+/// results. The default only requires a profit.
+#[derive(Debug, Clone, Default)]
+pub struct ScanFilter {
+    min_profit: Coin,
+    min_roi: Decimal,
+    min_buy_quantity: u32,
+    min_sell_quantity: u32,
+    max_unit_price: Option<Coin>,
+    max_spread_percent: Option<Decimal>,
+    max_volatility: Option<Decimal>,
+    min_bid_stability: Option<Decimal>,
+    min_ask_stability: Option<Decimal>,
+    whitelist: Option<HashSet<ItemId>>,
+    blacklist: HashSet<ItemId>,
+}
+
+impl ScanFilter {
+    /// Requires a profit per item of at least `profit`.
+    pub fn min_profit(mut self, profit: Coin) -> Self {
+        self.min_profit = profit;
+        self
+    }
+
+    /// Requires a return on investment of at least `roi`, e.g. 0.1 for 10%.
+    pub fn min_roi(mut self, roi: Decimal) -> Self {
+        self.min_roi = roi;
+        self
+    }
+
+    /// Requires at least `quantity` items wanted at the highest buy order.
+    pub fn min_buy_quantity(mut self, quantity: u32) -> Self {
+        self.min_buy_quantity = quantity;
+        self
+    }
+
+    /// Requires at least `quantity` items offered at the lowest sell offer.
+    pub fn min_sell_quantity(mut self, quantity: u32) -> Self {
+        self.min_sell_quantity = quantity;
+        self
+    }
+
+    /// Leaves out items costing more than `price` to buy.
+    pub fn max_unit_price(mut self, price: Coin) -> Self {
+        self.max_unit_price = Some(price);
+        self
+    }
+
+    /// Leaves out items whose spread is wider than `percent` of the mid-price, which often
+    /// means few trades.
+    pub fn max_spread_percent(mut self, percent: Decimal) -> Self {
+        self.max_spread_percent = Some(percent);
+        self
+    }
+
+    /// Leaves out items whose [`ItemMetrics::volatility`] is above `volatility`.
+    pub fn max_volatility(mut self, volatility: Decimal) -> Self {
+        self.max_volatility = Some(volatility);
+        self
+    }
+
+    /// Requires an [`ItemMetrics::bid_stability`] of at least `stability`, from 0 to 1.
+    pub fn min_bid_stability(mut self, stability: Decimal) -> Self {
+        self.min_bid_stability = Some(stability);
+        self
+    }
+
+    /// Requires an [`ItemMetrics::ask_stability`] of at least `stability`, from 0 to 1.
+    pub fn min_ask_stability(mut self, stability: Decimal) -> Self {
+        self.min_ask_stability = Some(stability);
+        self
+    }
+
+    /// Only considers `items`.
+    pub fn whitelist(mut self, items: impl IntoIterator<Item = ItemId>) -> Self {
+        self.whitelist = Some(items.into_iter().collect());
+        self
+    }
+
+    /// Never considers `items`.
+    pub fn blacklist(mut self, items: impl IntoIterator<Item = ItemId>) -> Self {
+        self.blacklist = items.into_iter().collect();
+        self
+    }
+
+    /// Whether `opportunity` meets every criterion.
+    pub fn matches(&self, opportunity: &Opportunity) -> bool {
+        self.matches_listing(opportunity)
+            && opportunity.profit > Coin::ZERO
+            && opportunity.profit >= self.min_profit
+            && opportunity.roi >= self.min_roi
+    }
+
+    /// Whether `opportunity` meets the criteria that don't depend on its profit, which
+    /// vendor values can change.
+    fn matches_listing(&self, opportunity: &Opportunity) -> bool {
+        let listed = match &self.whitelist {
+            Some(whitelist) => whitelist.contains(&opportunity.item_id),
+            None => true,
+        };
+
+        listed
+            && !self.blacklist.contains(&opportunity.item_id)
+            && opportunity.buy_quantity >= self.min_buy_quantity
+            && opportunity.sell_quantity >= self.min_sell_quantity
+            && self
+                .max_unit_price
+                .is_none_or(|max| opportunity.buy_price <= max)
+            && self.max_spread_percent.is_none_or(|max| {
+                opportunity
+                    .spread_percent()
+                    .is_some_and(|spread| spread <= max)
+            })
+    }
+
+    /// Whether `metrics` meet the criteria based on price history. Criteria on metrics that
+    /// weren't computed, for lack of history, aren't met.
+    pub fn matches_metrics(&self, metrics: Option<&ItemMetrics>) -> bool {
+        let at_most = |max: Option<Decimal>, value: Option<Decimal>| {
+            max.is_none_or(|max| value.is_some_and(|value| value <= max))
+        };
+        let at_least = |min: Option<Decimal>, value: Option<Decimal>| {
+            min.is_none_or(|min| value.is_some_and(|value| value >= min))
+        };
+
+        at_most(self.max_volatility, metrics.and_then(|m| m.volatility))
+            && at_least(
+                self.min_bid_stability,
+                metrics.and_then(|m| m.bid_stability),
+            )
+            && at_least(
+                self.min_ask_stability,
+                metrics.and_then(|m| m.ask_stability),
+            )
+    }
+}
+
+/// The `n` most profitable flips among `prices` that match `filter`, most profitable first.
+/// Items are never sold for less than their `vendor_values`. Without price history, no
+/// item meets criteria on volatility or stability; see [`top_opportunities_with_metrics`].
+pub fn top_opportunities<'a, Prices>(
+    prices: Prices,
+    vendor_values: &HashMap<ItemId, Coin>,
+    fees: &FeeSchedule,
+    filter: &ScanFilter,
+    n: usize,
+) -> Vec<Opportunity>
+where
+    Prices: IntoIterator<Item = &'a Price>,
+{
+    top_opportunities_with_metrics(prices, vendor_values, &HashMap::new(), fees, filter, n)
+}
+
+/// Like [`top_opportunities`], also checking `filter`'s criteria on the `metrics` of each
+/// item.
+pub fn top_opportunities_with_metrics<'a, Prices>(
+    prices: Prices,
+    vendor_values: &HashMap<ItemId, Coin>,
+    metrics: &HashMap<ItemId, ItemMetrics>,
+    fees: &FeeSchedule,
+    filter: &ScanFilter,
+    n: usize,
+) -> Vec<Opportunity>
+where
+    Prices: IntoIterator<Item = &'a Price>,
+{
+    let mut opportunities: Vec<Opportunity> = prices
+        .into_iter()
+        .filter_map(|price| {
+            Opportunity::from_price(price, vendor_values.get(&price.id).copied(), fees)
+        })
+        .filter(|opportunity| {
+            filter.matches(opportunity) && filter.matches_metrics(metrics.get(&opportunity.item_id))
+        })
+        .collect();
+
+    opportunities.sort_unstable_by(|a, b| b.profit.cmp(&a.profit).then(b.roi.cmp(&a.roi)));
+    opportunities.truncate(n);
+    opportunities
+}
+
+/// Error fetching the prices or items a scan needs.
+#[derive(thiserror::Error, Debug)]
+pub enum ScanError {
+    #[error("failed to fetch prices: {0}")]
+    Prices(#[from] PaginatedGetError),
+    #[error("failed to fetch vendor values: {0}")]
+    VendorValues(#[from] GetError),
+}
+
+/// Fetches the prices of every item and finds the `n` most profitable flips matching
+/// `filter`. The vendor values of every item meeting the criteria of `filter` that don't
+/// depend on profit are fetched as well (from the client's disk cache, if any), so no item
+/// is sold for less than a merchant pays, and items only profitable when sold to a
+/// merchant are found too.
+/// Corresponds to paginated GET /v2/commerce/prices and GET /v2/items
+pub async fn scan_market(
+    client: &Client,
+    fees: &FeeSchedule,
+    filter: &ScanFilter,
+    n: usize,
+) -> Result<Vec<Opportunity>, ScanError> {
+    let prices = crate::api::prices::get_all(client).await?;
+
+    let candidates: Vec<ItemId> = prices
+        .iter()
+        .filter_map(|price| Opportunity::from_price(price, None, fees))
+        .filter(|opportunity| filter.matches_listing(opportunity))
+        .map(|opportunity| opportunity.item_id)
+        .collect();
+    let vendor_values = super::get_vendor_values(client, &candidates).await?;
+
+    Ok(top_opportunities(&prices, &vendor_values, fees, filter, n))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::transport::{Request, Response, Transport, TransportFuture};
+    use rust_decimal_macros::dec;
+
+    fn price(id: u32, buy: u32, sell: u32) -> Price {
+        serde_json::from_value(serde_json::json!({
+            "id": id,
+            "whitelisted": true,
+            "buys": { "unit_price": buy, "quantity": 100 },
+            "sells": { "unit_price": sell, "quantity": 50 },
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn finds_most_profitable_flips() {
+        let prices = [
+            price(1, 100, 200),
+            price(2, 1000, 1100),
+            price(3, 5000, 8000),
+            price(4, 0, 500),
+            price(5, 100, 150),
+        ];
+
+        let top = top_opportunities(
+            &prices,
+            &HashMap::new(),
+            &FeeSchedule::default(),
+            &ScanFilter::default(),
+            2,
+        );
+        let ids: Vec<u32> = top
+            .iter()
+            .map(|opportunity| opportunity.item_id.0)
+            .collect();
+        assert_eq!(ids, vec![3, 1]);
+
+        // 8000 - 400 listing fee - 800 exchange fee - 5000
+        assert_eq!(top[0].profit, Coin(1800));
+        assert_eq!(top[0].roi, dec!(0.36));
+        assert_eq!(top[1].profit, Coin(70));
+    }
+
+    #[test]
+    fn never_sells_under_vendor_value() {
+        // Listing at 200 nets 170, listing at 8000 nets 6800
+        let prices = [price(1, 100, 200), price(3, 5000, 8000)];
+        let vendor_values = HashMap::from([(ItemId(1), Coin(250)), (ItemId(3), Coin(10))]);
+
+        let top = top_opportunities(
+            &prices,
+            &vendor_values,
+            &FeeSchedule::default(),
+            &ScanFilter::default(),
+            2,
+        );
+        assert_eq!(top[0].item_id, ItemId(3));
+        assert!(!top[0].sell_to_vendor);
+        assert_eq!(top[0].profit, Coin(1800));
+        assert_eq!(top[1].item_id, ItemId(1));
+        assert!(top[1].sell_to_vendor);
+        assert_eq!(top[1].profit, Coin(150));
+    }
+
+    #[test]
+    fn filter_drops_unwanted_items() {
+        let prices = [
+            price(1, 100, 200),
+            price(3, 5000, 8000),
+            price(5, 100, 150),
+            price(6, 300, 600),
+        ];
+        let scan = |filter: ScanFilter| -> Vec<u32> {
+            top_opportunities(
+                &prices,
+                &HashMap::new(),
+                &FeeSchedule::default(),
+                &filter,
+                10,
+            )
+            .iter()
+            .map(|opportunity| opportunity.item_id.0)
+            .collect()
+        };
+
+        assert_eq!(scan(ScanFilter::default()), vec![3, 6, 1, 5]);
+        assert_eq!(
+            scan(ScanFilter::default().min_profit(Coin(100))),
+            vec![3, 6]
+        );
+        assert_eq!(scan(ScanFilter::default().min_roi(dec!(0.5))), vec![6, 1]);
+        assert_eq!(
+            scan(ScanFilter::default().max_unit_price(Coin(1000))),
+            vec![6, 1, 5]
+        );
+        assert_eq!(
+            scan(ScanFilter::default().min_sell_quantity(51)),
+            Vec::<u32>::new()
+        );
+        // Spreads of 66.7%, 46.2%, 40% and 66.7%
+        assert_eq!(
+            scan(ScanFilter::default().max_spread_percent(dec!(50))),
+            vec![3, 5]
+        );
+        assert_eq!(
+            scan(
+                ScanFilter::default()
+                    .whitelist([ItemId(1), ItemId(3), ItemId(5)])
+                    .blacklist([ItemId(3)])
+            ),
+            vec![1, 5]
+        );
+    }
+
+    #[test]
+    fn filter_on_metrics() {
+        let prices = [price(1, 100, 200), price(3, 5000, 8000)];
+        let metrics = |id, volatility: Option<Decimal>| ItemMetrics {
+            item_id: ItemId(id),
+            spread_percent: None,
+            volatility,
+            bid_stability: Some(dec!(0.8)),
+            ask_stability: Some(dec!(0.2)),
+        };
+        let metrics = HashMap::from([
+            (ItemId(1), metrics(1, Some(dec!(2)))),
+            (ItemId(3), metrics(3, None)),
+        ]);
+        let scan = |filter: ScanFilter| -> Vec<u32> {
+            top_opportunities_with_metrics(
+                &prices,
+                &HashMap::new(),
+                &metrics,
+                &FeeSchedule::default(),
+                &filter,
+                10,
+            )
+            .iter()
+            .map(|opportunity| opportunity.item_id.0)
+            .collect()
+        };
+
+        assert_eq!(scan(ScanFilter::default()), vec![3, 1]);
+        assert_eq!(scan(ScanFilter::default().max_volatility(dec!(5))), vec![1]);
+        assert_eq!(
+            scan(ScanFilter::default().min_bid_stability(dec!(0.5))),
+            vec![3, 1]
+        );
+        assert_eq!(
+            scan(ScanFilter::default().min_ask_stability(dec!(0.5))),
+            Vec::<u32>::new()
+        );
+    }
+
+    /// Serves one page of prices, and items with a vendor value of 150 for item 1.
+    #[derive(Debug)]
+    struct Market;
+
+    impl Transport for Market {
+        fn send(&self, request: Request) -> TransportFuture<'_> {
+            Box::pin(async move {
+                let mut headers = reqwest::header::HeaderMap::new();
+                let body = if request.url.contains("/v2/commerce/prices") {
+                    for (name, value) in [
+                        ("X-Page-Size", "200"),
+                        ("X-Page-Total", "1"),
+                        ("X-Result-Count", "2"),
+                        ("X-Result-Total", "2"),
+                    ] {
+                        headers.insert(name, value.parse().unwrap());
+                    }
+                    serde_json::json!([
+                        {
+                            "id": 1,
+                            "buys": { "unit_price": 100, "quantity": 10 },
+                            "sells": { "unit_price": 105, "quantity": 10 },
+                        },
+                        {
+                            "id": 2,
+                            "buys": { "unit_price": 100, "quantity": 10 },
+                            "sells": { "unit_price": 200, "quantity": 10 },
+                        },
+                    ])
+                } else {
+                    let item = |id: u32, vendor_value: u32| {
+                        serde_json::json!({
+                            "id": id,
+                            "name": "Item",
+                            "type": "Trophy",
+                            "rarity": "Basic",
+                            "level": 0,
+                            "vendor_value": vendor_value,
+                            "flags": [],
+                            "game_types": [],
+                            "restrictions": [],
+                            "chat_link": "[&AgEAAAA=]",
+                        })
+                    };
+                    serde_json::json!([item(1, 150), item(2, 0)])
+                };
+
+                Ok(Response {
+                    status: reqwest::StatusCode::OK,
+                    headers,
+                    body: serde_json::to_vec(&body).unwrap().into(),
+                })
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn scan_finds_items_worth_selling_to_merchants() {
+        let client = Client::builder().transport(Market).build().unwrap();
+
+        let top = scan_market(&client, &FeeSchedule::default(), &ScanFilter::default(), 10)
+            .await
+            .unwrap();
+        let found: Vec<(u32, Coin, bool)> = top
+            .iter()
+            .map(|opportunity| {
+                (
+                    opportunity.item_id.0,
+                    opportunity.profit,
+                    opportunity.sell_to_vendor,
+                )
+            })
+            .collect();
+        // Listing item 1 at 105 nets 89, a merchant pays 150
+        assert_eq!(found, vec![(2, Coin(70), false), (1, Coin(50), true)]);
+    }
+}
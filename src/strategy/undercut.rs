@@ -0,0 +1,184 @@
+//! Checking the account's sell offers against the trading post, to find the ones other
+//! sellers have undercut and what relisting them would cost.
+
+use std::collections::{BTreeMap, HashMap};
+
+use super::FeeSchedule;
+use crate::{
+    api::{
+        listings::{self, Listings},
+        transactions::{self, Transaction},
+        ItemId,
+    },
+    client::{Client, GetError, PaginatedGetError},
+    coin::Coin,
+};
+
+/// A sell offer with cheaper offers of other sellers ahead of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Undercut {
+    /// The id of the sell offer's transaction.
+    pub transaction_id: u64,
+    pub item_id: ItemId,
+    /// The price of the sell offer, per item.
+    pub price: Coin,
+    /// Items left to sell.
+    pub quantity: u32,
+    /// The lowest price other sellers offer the item at.
+    pub lowest_price: Coin,
+    /// How much cheaper the lowest offer is, per item.
+    pub undercut_by: Coin,
+    /// Items other sellers offer below the sell offer's price, which sell first.
+    pub quantity_ahead: u32,
+    /// The price to relist at to be the lowest offer again: a copper below the lowest offer.
+    pub relist_price: Coin,
+    /// The listing fee of relisting every item at `relist_price`. The listing fee of the
+    /// current offer isn't refunded when cancelling it.
+    pub relist_cost: Coin,
+}
+
+/// Error fetching the account's sell offers or their listings.
+#[derive(thiserror::Error, Debug)]
+pub enum UndercutError {
+    #[error("failed to fetch sell offers: {0}")]
+    Transactions(#[from] PaginatedGetError),
+    #[error("failed to fetch listings: {0}")]
+    Listings(#[from] GetError),
+}
+
+/// The sell offers among `sells` that have been undercut, according to the `listings` of
+/// their items, in the order of `sells`. The account's own offers in the listings don't
+/// count as undercutting. Items without listings are left out.
+pub fn find_undercuts(
+    sells: &[Transaction],
+    listings: &HashMap<ItemId, Listings>,
+    fees: &FeeSchedule,
+) -> Vec<Undercut> {
+    let mut own: HashMap<ItemId, BTreeMap<Coin, u32>> = HashMap::new();
+    for sell in sells {
+        *own.entry(sell.item_id)
+            .or_default()
+            .entry(sell.price)
+            .or_default() += sell.quantity;
+    }
+
+    sells
+        .iter()
+        .filter_map(|sell| {
+            let own = &own[&sell.item_id];
+            let competing: Vec<(Coin, u32)> = listings
+                .get(&sell.item_id)?
+                .sells
+                .iter()
+                .filter(|level| level.unit_price < sell.price)
+                .map(|level| {
+                    let own_quantity = own.get(&level.unit_price).copied().unwrap_or_default();
+                    (
+                        level.unit_price,
+                        level.quantity.saturating_sub(own_quantity),
+                    )
+                })
+                .filter(|(_, quantity)| *quantity > 0)
+                .collect();
+
+            let lowest_price = competing.iter().map(|(price, _)| *price).min()?;
+            let relist_price = (lowest_price - Coin(1)).max(Coin(1));
+            let relist_fee = Coin::try_from(fees.listing_fee(relist_price.into())).ok()?;
+
+            Some(Undercut {
+                transaction_id: sell.id,
+                item_id: sell.item_id,
+                price: sell.price,
+                quantity: sell.quantity,
+                lowest_price,
+                undercut_by: sell.price - lowest_price,
+                quantity_ahead: competing.iter().map(|(_, quantity)| quantity).sum(),
+                relist_price,
+                relist_cost: relist_fee * sell.quantity,
+            })
+        })
+        .collect()
+}
+
+/// Fetches the account's sell offers and the listings of their items, and finds the offers
+/// that have been undercut.
+/// Corresponds to GET /v2/commerce/transactions/current/sells and /v2/commerce/listings
+/// Requires authentication: 'account', 'tradingpost' scopes.
+pub async fn fetch_undercuts(
+    client: &Client,
+    fees: &FeeSchedule,
+) -> Result<Vec<Undercut>, UndercutError> {
+    let sells = transactions::get_current_sells(client).await?;
+    let items: Vec<ItemId> = sells.iter().map(|sell| sell.item_id).collect();
+    let listings = listings::get_listings_chunked(client, &items)
+        .await?
+        .found
+        .into_iter()
+        .map(|listings| (listings.id, listings))
+        .collect();
+
+    Ok(find_undercuts(&sells, &listings, fees))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sell(id: u64, item_id: u32, price: i64, quantity: u32) -> Transaction {
+        Transaction {
+            id,
+            item_id: ItemId(item_id),
+            price: Coin(price),
+            quantity,
+            created: String::new(),
+            purchased: None,
+        }
+    }
+
+    fn listings(id: u32, sells: &[(u32, u32)]) -> (ItemId, Listings) {
+        let sells: Vec<_> = sells
+            .iter()
+            .map(|(price, quantity)| {
+                serde_json::json!({ "listings": 1, "unit_price": price, "quantity": quantity })
+            })
+            .collect();
+        let listings = serde_json::from_value(serde_json::json!({
+            "id": id,
+            "buys": [],
+            "sells": sells,
+        }))
+        .unwrap();
+        (ItemId(id), listings)
+    }
+
+    #[test]
+    fn finds_offers_undercut_by_others() {
+        let sells = [
+            sell(1, 10, 500, 5),
+            sell(2, 10, 450, 2),
+            sell(3, 20, 100, 1),
+            sell(4, 30, 80, 3),
+        ];
+        let listings = HashMap::from([
+            // Own offers at 450 and 500, and 3 items of others at 450
+            listings(10, &[(440, 4), (450, 5), (500, 5)]),
+            // Own offer is the lowest
+            listings(20, &[(100, 1), (120, 7)]),
+        ]);
+
+        let undercuts = find_undercuts(&sells, &listings, &FeeSchedule::default());
+        assert_eq!(undercuts.len(), 2);
+
+        assert_eq!(undercuts[0].transaction_id, 1);
+        assert_eq!(undercuts[0].lowest_price, Coin(440));
+        assert_eq!(undercuts[0].undercut_by, Coin(60));
+        assert_eq!(undercuts[0].quantity_ahead, 7);
+        assert_eq!(undercuts[0].relist_price, Coin(439));
+        // 5% of 439, rounded to 22, for each of 5 items
+        assert_eq!(undercuts[0].relist_cost, Coin(110));
+
+        assert_eq!(undercuts[1].transaction_id, 2);
+        assert_eq!(undercuts[1].undercut_by, Coin(10));
+        assert_eq!(undercuts[1].quantity_ahead, 4);
+    }
+}